@@ -0,0 +1,198 @@
+//! Derive macros for `atomik`
+//!
+//!## Macros
+//!
+//!- `AtomicEnum` - Implements `atomik::AtomicEnum` for fieldless enum with primitive integer `#[repr]`.
+//!- `AtomicPack` - Implements `atomik::AtomicPack` for struct of primitive integers and `bool`, packing it into integer word.
+
+#![warn(missing_docs)]
+#![allow(clippy::style)]
+
+use proc_macro::TokenStream;
+use quote::quote;
+
+const REPRS: &[&str] = &["u8", "u16", "u32", "u64", "i8", "i16", "i32", "i64"];
+
+fn find_repr(input: &syn::DeriveInput) -> syn::Result<syn::Ident> {
+    for attr in input.attrs.iter().filter(|attr| attr.path().is_ident("repr")) {
+        let mut result = None;
+        attr.parse_nested_meta(|meta| {
+            if let Some(ident) = meta.path.get_ident() {
+                if REPRS.iter().any(|repr| ident == repr) {
+                    result = Some(ident.clone());
+                }
+            }
+            Ok(())
+        })?;
+
+        if let Some(result) = result {
+            return Ok(result);
+        }
+    }
+
+    Err(syn::Error::new_spanned(&input.ident, "AtomicEnum requires #[repr] with one of u8, u16, u32, u64, i8, i16, i32 or i64"))
+}
+
+fn derive_atomic_enum(input: syn::DeriveInput) -> syn::Result<proc_macro2::TokenStream> {
+    let data = match &input.data {
+        syn::Data::Enum(data) => data,
+        _ => return Err(syn::Error::new_spanned(&input.ident, "AtomicEnum can be derived only for enums")),
+    };
+    if !input.generics.params.is_empty() {
+        return Err(syn::Error::new_spanned(&input.generics, "AtomicEnum cannot be derived for generic enum"));
+    }
+
+    let repr = find_repr(&input)?;
+    let name = &input.ident;
+    let mut variants = Vec::with_capacity(data.variants.len());
+    for variant in data.variants.iter() {
+        if !matches!(variant.fields, syn::Fields::Unit) {
+            return Err(syn::Error::new_spanned(variant, "AtomicEnum requires enum without fields"));
+        }
+        variants.push(&variant.ident);
+    }
+
+    Ok(quote! {
+        unsafe impl ::atomik::AtomicEnum for #name {
+            type Repr = #repr;
+
+            #[inline]
+            fn to_repr(self) -> #repr {
+                self as #repr
+            }
+
+            #[inline]
+            fn from_repr(repr: #repr) -> Option<Self> {
+                #(
+                    if repr == #name::#variants as #repr {
+                        return Some(#name::#variants);
+                    }
+                )*
+                None
+            }
+        }
+    })
+}
+
+#[proc_macro_derive(AtomicEnum)]
+///Implements `atomik::AtomicEnum` for fieldless enum.
+///
+///Enum must be `Copy` and have `#[repr]` of primitive integer, which has size of 1, 2, 4 or 8 bytes.
+pub fn atomic_enum(input: TokenStream) -> TokenStream {
+    let input = syn::parse_macro_input!(input as syn::DeriveInput);
+    match derive_atomic_enum(input) {
+        Ok(output) => output.into(),
+        Err(error) => error.to_compile_error().into(),
+    }
+}
+
+//Width in bits and unsigned type of the same width, used to avoid sign extension
+fn field_width(ty: &syn::Type) -> Option<(u32, &'static str)> {
+    let ident = match ty {
+        syn::Type::Path(path) if path.qself.is_none() => path.path.get_ident()?,
+        _ => return None,
+    };
+
+    let result = match ident.to_string().as_str() {
+        "bool" | "u8" | "i8" => (8, "u8"),
+        "u16" | "i16" => (16, "u16"),
+        "u32" | "i32" => (32, "u32"),
+        "u64" | "i64" => (64, "u64"),
+        _ => return None,
+    };
+    Some(result)
+}
+
+fn field_is_bool(ty: &syn::Type) -> bool {
+    match ty {
+        syn::Type::Path(path) => path.path.is_ident("bool"),
+        _ => false,
+    }
+}
+
+fn derive_atomic_pack(input: syn::DeriveInput) -> syn::Result<proc_macro2::TokenStream> {
+    let data = match &input.data {
+        syn::Data::Struct(data) => data,
+        _ => return Err(syn::Error::new_spanned(&input.ident, "AtomicPack can be derived only for structs")),
+    };
+    if !input.generics.params.is_empty() {
+        return Err(syn::Error::new_spanned(&input.generics, "AtomicPack cannot be derived for generic struct"));
+    }
+    if data.fields.is_empty() {
+        return Err(syn::Error::new_spanned(&input.ident, "AtomicPack requires struct with fields"));
+    }
+
+    let name = &input.ident;
+    let mut shift = 0u32;
+    let mut packs = Vec::with_capacity(data.fields.len());
+    let mut unpacks = Vec::with_capacity(data.fields.len());
+    for (idx, field) in data.fields.iter().enumerate() {
+        let (width, unsigned) = match field_width(&field.ty) {
+            Some(width) => width,
+            None => return Err(syn::Error::new_spanned(&field.ty, "AtomicPack requires fields of bool, u8, u16, u32, u64, i8, i16, i32 or i64")),
+        };
+        let unsigned = syn::Ident::new(unsigned, proc_macro2::Span::call_site());
+        let ty = &field.ty;
+        let member = match &field.ident {
+            Some(ident) => syn::Member::Named(ident.clone()),
+            None => syn::Member::Unnamed(syn::Index::from(idx)),
+        };
+
+        packs.push((member.clone(), unsigned.clone(), shift));
+        let value = quote! { (packed >> #shift) as #unsigned };
+        let value = if field_is_bool(ty) {
+            quote! { #value != 0 }
+        } else {
+            quote! { #value as #ty }
+        };
+        unpacks.push(quote! { #member: #value });
+        shift += width;
+    }
+
+    let packed = match shift {
+        0..=8 => "u8",
+        9..=16 => "u16",
+        17..=32 => "u32",
+        33..=64 => "u64",
+        _ => return Err(syn::Error::new_spanned(&input.ident, "AtomicPack requires fields to fit into 64 bits")),
+    };
+    let packed = syn::Ident::new(packed, proc_macro2::Span::call_site());
+    let packs = packs.iter().map(|(member, unsigned, shift)| quote! { ((self.#member as #unsigned as #packed) << #shift) });
+
+    Ok(quote! {
+        impl ::atomik::AtomicPack for #name {
+            type Packed = #packed;
+
+            #[inline]
+            #[allow(clippy::unnecessary_cast, clippy::identity_op)]
+            fn pack(self) -> #packed {
+                0 #(| #packs)*
+            }
+
+            #[inline]
+            #[allow(clippy::unnecessary_cast, clippy::identity_op)]
+            fn unpack(packed: #packed) -> Self {
+                Self {
+                    #(#unpacks,)*
+                }
+            }
+        }
+    })
+}
+
+#[proc_macro_derive(AtomicPack)]
+///Implements `atomik::AtomicPack` for struct.
+///
+///Struct must be `Copy` and consist of `bool`, `u8`, `u16`, `u32`, `u64`, `i8`, `i16`, `i32` or `i64` fields, fitting into 64 bits in total.
+///
+///Fields are packed in declaration order starting from the least significant bit, each occupying its own size (`bool` occupies 8 bits).
+///Packed word is the smallest of `u8`, `u16`, `u32` or `u64`, which fits all fields.
+///
+///E.g. `struct Rgba { r: u8, g: u8, b: u8, a: u8 }` is packed into `u32` with `r` in bits `0..8`, `g` in `8..16`, `b` in `16..24` and `a` in `24..32`.
+pub fn atomic_pack(input: TokenStream) -> TokenStream {
+    let input = syn::parse_macro_input!(input as syn::DeriveInput);
+    match derive_atomic_pack(input) {
+        Ok(output) => output.into(),
+        Err(error) => error.to_compile_error().into(),
+    }
+}
@@ -0,0 +1,45 @@
+use atomik::{Atomic, AtomicEnum, InvalidDiscriminant, Ordering};
+use atomik_derive::AtomicEnum;
+
+#[derive(AtomicEnum, Copy, Clone, Debug, PartialEq)]
+#[repr(u8)]
+enum State {
+    Idle,
+    Running = 5,
+    Stopped,
+}
+
+#[test]
+fn should_convert_enum_to_and_from_repr() {
+    assert_eq!(State::Idle.to_repr(), 0);
+    assert_eq!(State::Running.to_repr(), 5);
+    assert_eq!(State::Stopped.to_repr(), 6);
+
+    assert_eq!(State::from_repr(0), Some(State::Idle));
+    assert_eq!(State::from_repr(5), Some(State::Running));
+    assert_eq!(State::from_repr(6), Some(State::Stopped));
+    assert_eq!(State::from_repr(1), None);
+}
+
+#[test]
+fn should_access_atomic_enum() {
+    let state = Atomic::new(State::Idle);
+    assert_eq!(state.load_enum(Ordering::Acquire), Ok(State::Idle));
+    state.store_enum(State::Running, Ordering::Release);
+    assert_eq!(state.swap_enum(State::Stopped, Ordering::AcqRel), Ok(State::Running));
+    assert_eq!(state.compare_exchange_enum(State::Idle, State::Running, Ordering::AcqRel, Ordering::Acquire), Ok(Err(State::Stopped)));
+    assert_eq!(state.compare_exchange_enum(State::Stopped, State::Idle, Ordering::AcqRel, Ordering::Acquire), Ok(Ok(State::Stopped)));
+    assert_eq!(state.load_enum(Ordering::Acquire), Ok(State::Idle));
+}
+
+#[test]
+fn should_report_invalid_discriminant() {
+    let mut raw = Atomic::<u8>::new(2);
+    let state = unsafe {
+        &*(&mut raw as *mut Atomic<u8> as *const Atomic<State>)
+    };
+    assert_eq!(state.load_enum(Ordering::Acquire), Err(InvalidDiscriminant(2)));
+    assert_eq!(state.compare_exchange_enum(State::Idle, State::Running, Ordering::AcqRel, Ordering::Acquire), Err(InvalidDiscriminant(2)));
+    assert_eq!(state.swap_enum(State::Running, Ordering::AcqRel), Err(InvalidDiscriminant(2)));
+    assert_eq!(state.load_enum(Ordering::Acquire), Ok(State::Running));
+}
@@ -0,0 +1,38 @@
+use atomik::{AtomicPack, AtomicPacked, Ordering};
+use atomik_derive::AtomicPack;
+
+#[derive(AtomicPack, Copy, Clone, Debug, PartialEq)]
+struct Rgba {
+    r: u8,
+    g: u8,
+    b: u8,
+    a: u8,
+}
+
+#[derive(AtomicPack, Copy, Clone, Debug, PartialEq)]
+struct Entry(i16, bool, u32);
+
+#[test]
+fn should_pack_fields_from_least_significant_bit() {
+    let color = Rgba { r: 0x11, g: 0x22, b: 0x33, a: 0x44 };
+    assert_eq!(color.pack(), 0x4433_2211u32);
+    assert_eq!(Rgba::unpack(0x4433_2211), color);
+
+    let entry = Entry(-2, true, 0xdead_beef);
+    assert_eq!(entry.pack(), 0x00de_adbe_ef01_fffe_u64);
+    assert_eq!(Entry::unpack(entry.pack()), entry);
+}
+
+#[test]
+fn should_swap_packed_color_atomically() {
+    let red = Rgba { r: 255, g: 0, b: 0, a: 255 };
+    let blue = Rgba { r: 0, g: 0, b: 255, a: 255 };
+    let color = AtomicPacked::new(red);
+
+    assert_eq!(color.load(Ordering::Acquire), red);
+    assert_eq!(color.compare_exchange(red, blue, Ordering::AcqRel, Ordering::Acquire), Ok(red));
+    assert_eq!(color.compare_exchange(red, blue, Ordering::AcqRel, Ordering::Acquire), Err(blue));
+    assert_eq!(color.swap(red, Ordering::AcqRel), blue);
+    color.store(blue, Ordering::Release);
+    assert_eq!(color.into_inner(), blue);
+}
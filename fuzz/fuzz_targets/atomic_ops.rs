@@ -0,0 +1,130 @@
+#![no_main]
+
+use atomik::Atomic;
+use core::sync::atomic::Ordering;
+use libfuzzer_sys::fuzz_target;
+
+const LOAD: [Ordering; 3] = [Ordering::Relaxed, Ordering::Acquire, Ordering::SeqCst];
+const STORE: [Ordering; 3] = [Ordering::Relaxed, Ordering::Release, Ordering::SeqCst];
+const RMW: [Ordering; 5] = [Ordering::Relaxed, Ordering::Acquire, Ordering::Release, Ordering::AcqRel, Ordering::SeqCst];
+
+struct Input<'a> {
+    data: &'a [u8],
+}
+
+impl<'a> Input<'a> {
+    fn byte(&mut self) -> Option<u8> {
+        let (first, rest) = self.data.split_first()?;
+        self.data = rest;
+        Some(*first)
+    }
+
+    fn u64(&mut self) -> Option<u64> {
+        let mut bytes = [0u8; 8];
+        for byte in bytes.iter_mut() {
+            *byte = self.byte()?;
+        }
+        Some(u64::from_le_bytes(bytes))
+    }
+
+    fn ordering(&mut self, set: &[Ordering]) -> Option<Ordering> {
+        self.byte().map(|idx| set[idx as usize % set.len()])
+    }
+}
+
+//Drives random sequence of operations, comparing results against plain shadow value
+macro_rules! drive {
+    ($input:ident, $init:expr, $convert:expr, $check:expr) => {{
+        let convert = $convert;
+        let check = $check;
+        let mut shadow = $init;
+        let atomic = Atomic::new(shadow);
+
+        while let Some(op) = $input.byte() {
+            let val = match $input.u64() {
+                Some(val) => convert(val),
+                None => break,
+            };
+            match op % 6 {
+                0 => {
+                    let order = match $input.ordering(&LOAD) {
+                        Some(order) => order,
+                        None => break,
+                    };
+                    let loaded = atomic.load(order);
+                    assert!(check(loaded));
+                    assert_eq!(loaded, shadow);
+                },
+                1 => {
+                    let order = match $input.ordering(&STORE) {
+                        Some(order) => order,
+                        None => break,
+                    };
+                    atomic.store(val, order);
+                    shadow = val;
+                },
+                2 => {
+                    let order = match $input.ordering(&RMW) {
+                        Some(order) => order,
+                        None => break,
+                    };
+                    assert_eq!(atomic.swap(val, order), shadow);
+                    shadow = val;
+                },
+                3 => {
+                    let (success, failure) = match ($input.ordering(&RMW), $input.ordering(&LOAD)) {
+                        (Some(success), Some(failure)) => (success, failure),
+                        _ => break,
+                    };
+                    let current = if $input.byte().unwrap_or(0) & 1 == 0 { shadow } else { val };
+                    let result = atomic.compare_exchange(current, val, success, failure);
+                    if current == shadow {
+                        assert_eq!(result, Ok(shadow));
+                        shadow = val;
+                    } else {
+                        assert_eq!(result, Err(shadow));
+                    }
+                },
+                4 => {
+                    let (set, fetch) = match ($input.ordering(&RMW), $input.ordering(&LOAD)) {
+                        (Some(set), Some(fetch)) => (set, fetch),
+                        _ => break,
+                    };
+                    let result = atomic.fetch_update(set, fetch, |prev| {
+                        assert!(check(prev));
+                        Some(val)
+                    });
+                    assert_eq!(result, Ok(shadow));
+                    shadow = val;
+                },
+                _ => {
+                    let (set, fetch) = match ($input.ordering(&RMW), $input.ordering(&LOAD)) {
+                        (Some(set), Some(fetch)) => (set, fetch),
+                        _ => break,
+                    };
+                    assert_eq!(atomic.fetch_update(set, fetch, |_| None), Err(shadow));
+                },
+            }
+        }
+
+        assert_eq!(atomic.into_inner(), shadow);
+    }};
+}
+
+fuzz_target!(|data: &[u8]| {
+    let mut input = Input {
+        data,
+    };
+
+    match input.byte().unwrap_or(0) % 4 {
+        0 => drive!(input, 0u64, |val: u64| val, |_: u64| true),
+        1 => drive!(input, 0i32, |val: u64| val as i32, |_: i32| true),
+        2 => drive!(input, false, |val: u64| val & 1 == 1, |val: bool| {
+            let byte = unsafe { *(&val as *const bool as *const u8) };
+            byte <= 1
+        }),
+        _ => drive!(input, '\0', |val: u64| char::from_u32(val as u32).unwrap_or(char::REPLACEMENT_CHARACTER), |val: char| {
+            char::from_u32(val as u32).is_some()
+        }),
+    }
+});
@@ -0,0 +1,54 @@
+//!Debug registry of memory, reinterpreted as atomics.
+//!
+//!Accessing the same memory via atomics of different width is UB, so registry asserts that overlapping regions have the same width.
+//!
+//!Checks are only performed in debug builds, in release builds registry is empty.
+
+#[cfg(debug_assertions)]
+use std::sync::Mutex;
+#[cfg(debug_assertions)]
+use std::vec::Vec;
+
+//Start address and width of each alive `AtomicBytesRef`
+#[cfg(debug_assertions)]
+static REGISTRY: Mutex<Vec<(usize, usize)>> = Mutex::new(Vec::new());
+
+//Registers atomic of `width` bytes at `addr`, panicking if it overlaps with atomic of different width.
+#[cfg(debug_assertions)]
+pub(crate) fn register(addr: usize, width: usize) {
+    let conflict = {
+        let mut registry = REGISTRY.lock().unwrap_or_else(|error| error.into_inner());
+        let conflict = registry.iter().find(|(reg_addr, reg_width)| {
+            let is_overlap = addr < reg_addr + reg_width && *reg_addr < addr + width;
+            is_overlap && (*reg_addr != addr || *reg_width != width)
+        }).copied();
+
+        if conflict.is_none() {
+            registry.push((addr, width));
+        }
+        conflict
+    };
+
+    if let Some((reg_addr, reg_width)) = conflict {
+        panic!("Atomic of {} bytes at {:#x} overlaps with atomic of {} bytes at {:#x}", width, addr, reg_width, reg_addr);
+    }
+}
+
+//Removes single registration of atomic of `width` bytes at `addr`.
+#[cfg(debug_assertions)]
+pub(crate) fn unregister(addr: usize, width: usize) {
+    let mut registry = REGISTRY.lock().unwrap_or_else(|error| error.into_inner());
+    if let Some(idx) = registry.iter().position(|entry| *entry == (addr, width)) {
+        registry.swap_remove(idx);
+    }
+}
+
+///Forgets all registered atomics.
+///
+///Normally it is not needed, as memory is unregistered once `AtomicBytesRef` is dropped.
+///
+///No-op in release builds.
+pub fn clear_alias_registry() {
+    #[cfg(debug_assertions)]
+    REGISTRY.lock().unwrap_or_else(|error| error.into_inner()).clear();
+}
@@ -0,0 +1,98 @@
+use core::fmt;
+use alloc::sync::Arc;
+
+use crate::{Atomic, Ordering};
+
+///Atomic `Arc<T>`, which can be loaded and replaced by multiple threads.
+///
+///Stores raw pointer of `Arc`, owning one strong reference.
+///Readers register themselves in counter for the short window between loading pointer and incrementing its strong count,
+///while writers wait for this counter to become zero before releasing displaced `Arc`.
+///
+///This is simple scheme without hazard pointers or epochs, hence its limitation:
+///writers spin while any reader is within the window, and constant stream of readers may delay writers indefinitely.
+///It is suitable for read-mostly data (e.g. configuration), which is rarely replaced.
+pub struct AtomicArc<T> {
+    ptr: Atomic<*mut T>,
+    readers: Atomic<usize>,
+}
+
+unsafe impl<T: Send + Sync> Send for AtomicArc<T> {}
+unsafe impl<T: Send + Sync> Sync for AtomicArc<T> {}
+
+impl<T> AtomicArc<T> {
+    #[inline]
+    ///Creates a new instance
+    pub fn new(value: Arc<T>) -> Self {
+        Self {
+            ptr: Atomic::new(Arc::into_raw(value) as *mut T),
+            readers: Atomic::new(0),
+        }
+    }
+
+    ///Loads `Arc`, incrementing its strong count.
+    pub fn load(&self) -> Arc<T> {
+        //SeqCst is required to order registration of reader with writer's swap and subsequent check of readers
+        self.readers.fetch_add(1, Ordering::SeqCst);
+        let ptr = self.ptr.load(Ordering::SeqCst);
+        //Writer cannot release `ptr` until reader is unregistered
+        let result = unsafe {
+            Arc::increment_strong_count(ptr);
+            Arc::from_raw(ptr)
+        };
+        self.readers.fetch_sub(1, Ordering::Release);
+        result
+    }
+
+    #[inline]
+    ///Stores `Arc`, releasing the previous one.
+    pub fn store(&self, value: Arc<T>) {
+        drop(self.swap(value));
+    }
+
+    ///Stores `Arc`, returning the previous one.
+    ///
+    ///Waits until readers, which could have observed the previous one, increment its strong count.
+    pub fn swap(&self, value: Arc<T>) -> Arc<T> {
+        let prev = self.ptr.swap(Arc::into_raw(value) as *mut T, Ordering::SeqCst);
+        while self.readers.load(Ordering::SeqCst) != 0 {
+            core::hint::spin_loop();
+        }
+        unsafe {
+            Arc::from_raw(prev)
+        }
+    }
+
+    #[inline]
+    ///Consumes self and returns the contained `Arc`.
+    pub fn into_inner(self) -> Arc<T> {
+        let ptr = self.ptr.load(Ordering::Relaxed);
+        core::mem::forget(self);
+        unsafe {
+            Arc::from_raw(ptr)
+        }
+    }
+}
+
+impl<T> Drop for AtomicArc<T> {
+    #[inline]
+    fn drop(&mut self) {
+        unsafe {
+            drop(Arc::from_raw(*self.ptr.get_mut()));
+        }
+    }
+}
+
+impl<T: Default> Default for AtomicArc<T> {
+    #[inline(always)]
+    fn default() -> Self {
+        Self::new(Default::default())
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for AtomicArc<T> {
+    #[inline(always)]
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&self.load(), fmt)
+    }
+}
@@ -0,0 +1,34 @@
+use crate::{Atomic, Ordering};
+
+impl Atomic<u64> {
+    #[inline]
+    ///Adds to the current value, returning the previous value.
+    ///
+    ///Same as `fetch_add`, but on `x86_64` it is implemented as `lock xadd` via inline assembly, bypassing backend.
+    ///As `lock` prefixed instructions are sequentially consistent on `x86_64`, `order` is only used by fallback,
+    ///which is CAS loop on other targets.
+    pub fn fetch_add_asm(&self, val: u64, order: Ordering) -> u64 {
+        #[cfg(target_arch = "x86_64")]
+        {
+            let _ = order;
+            let mut prev = val;
+            unsafe {
+                core::arch::asm!(
+                    "lock xadd qword ptr [{ptr}], {val}",
+                    ptr = in(reg) self.inner_ptr(),
+                    val = inout(reg) prev,
+                    options(nostack),
+                );
+            }
+            prev
+        }
+
+        #[cfg(not(target_arch = "x86_64"))]
+        {
+            match self.fetch_update(order, crate::failure_ordering(order), |prev| Some(prev.wrapping_add(val))) {
+                Ok(prev) => prev,
+                Err(prev) => prev,
+            }
+        }
+    }
+}
@@ -0,0 +1,40 @@
+use core::ops::Deref;
+
+use crate::Atomic;
+
+///Atomic reference into byte buffer, created via `Atomic::from_bytes_mut`.
+///
+///With `debug-checks` and `std` features, debug builds track memory of the reference while it is alive,
+///so that overlapping atomics of different width can be detected.
+pub struct AtomicBytesRef<'a, T> {
+    atomic: &'a Atomic<T>,
+}
+
+impl<'a, T> AtomicBytesRef<'a, T> {
+    #[inline(always)]
+    pub(crate) fn new(atomic: &'a Atomic<T>) -> Self {
+        #[cfg(all(feature = "debug-checks", feature = "std", debug_assertions))]
+        crate::alias::register(atomic as *const Atomic<T> as usize, core::mem::size_of::<T>());
+
+        Self {
+            atomic,
+        }
+    }
+}
+
+impl<T> Deref for AtomicBytesRef<'_, T> {
+    type Target = Atomic<T>;
+
+    #[inline(always)]
+    fn deref(&self) -> &Self::Target {
+        self.atomic
+    }
+}
+
+impl<T> Drop for AtomicBytesRef<'_, T> {
+    #[inline]
+    fn drop(&mut self) {
+        #[cfg(all(feature = "debug-checks", feature = "std", debug_assertions))]
+        crate::alias::unregister(self.atomic as *const Atomic<T> as usize, core::mem::size_of::<T>());
+    }
+}
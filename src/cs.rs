@@ -0,0 +1,283 @@
+use core::{fmt, mem};
+use core::cell::UnsafeCell;
+
+use crate::{bits_eq, Ordering};
+
+#[repr(transparent)]
+///Generic atomic which always relies on critical section, regardless of crate's backend.
+///
+///This allows to mix it with native `Atomic` within the same binary (e.g. when value is shared with ISR on core that lacks atomic instructions).
+///
+///Size of `T` must be the same as one of `u8`, `u16`, `u32` or `u64`, but there is no alignment requirement.
+///
+///Every operation is performed within `critical_section::with`, therefore `Ordering` arguments are only accepted for API compatibility with `Atomic` and are otherwise ignored.
+pub struct CsAtomic<T> {
+    inner: UnsafeCell<T>
+}
+
+unsafe impl<T: Send> Sync for CsAtomic<T> {}
+impl<T: Copy + core::panic::RefUnwindSafe> core::panic::RefUnwindSafe for CsAtomic<T> {}
+
+impl<T: Default> Default for CsAtomic<T> {
+    #[inline(always)]
+    fn default() -> Self {
+        Self::new(Default::default())
+    }
+}
+
+impl<T> CsAtomic<T> {
+    const TYPE_SIZE: usize = {
+        let size = mem::size_of::<T>();
+        assert!(size == 1 || size == 2 || size == 4 || size == 8);
+        size
+    };
+
+    #[inline]
+    ///Creates a new instance
+    pub const fn new(value: T) -> CsAtomic<T> {
+        debug_assert!(Self::TYPE_SIZE <= mem::size_of::<u64>());
+
+        CsAtomic {
+            inner: UnsafeCell::new(value),
+        }
+    }
+}
+
+impl<T: Copy> CsAtomic<T> {
+    #[inline]
+    fn inner_ptr(&self) -> *mut T {
+        self.inner.get()
+    }
+
+    #[inline]
+    fn modify<F: FnOnce(T) -> T>(&self, cb: F) -> T {
+        critical_section::with(|_| unsafe {
+            let prev = *self.inner_ptr();
+            *self.inner_ptr() = cb(prev);
+            prev
+        })
+    }
+
+    ///Returns a mutable reference to the underlying type.
+    ///
+    ///This is safe because the mutable reference guarantees that no other threads are concurrently accessing the atomic data.
+    #[inline]
+    pub fn get_mut(&mut self) -> &mut T {
+        unsafe { &mut *self.inner_ptr() }
+    }
+
+    ///Consumes the atomic and returns the contained value.
+    ///
+    ///This is safe because passing `self` by value guarantees that no other threads are concurrently accessing the atomic data.
+    #[inline]
+    pub fn into_inner(self) -> T {
+        self.inner.into_inner()
+    }
+
+    #[inline]
+    ///Loads a value from the atomic.
+    pub fn load(&self, _order: Ordering) -> T {
+        critical_section::with(|_| unsafe {
+            *self.inner_ptr()
+        })
+    }
+
+    #[inline]
+    ///Stores a value into the atomic.
+    pub fn store(&self, val: T, _order: Ordering) {
+        critical_section::with(|_| unsafe {
+            *self.inner_ptr() = val;
+        })
+    }
+
+    #[inline]
+    ///Publishes value for readers.
+    ///
+    ///Equivalent of `Atomic::publish`.
+    pub fn publish(&self, val: T) {
+        self.store(val, Ordering::Release)
+    }
+
+    #[inline]
+    ///Loads value published by `publish`.
+    ///
+    ///Equivalent of `Atomic::consume`.
+    pub fn consume(&self) -> T {
+        self.load(Ordering::Acquire)
+    }
+
+    #[inline]
+    ///Stores a value into the atomic, returning the previous value.
+    pub fn swap(&self, val: T, _order: Ordering) -> T {
+        self.modify(|_| val)
+    }
+
+    ///Stores a value into the atomic if the current value is the same as the `current` value.
+    ///
+    ///The return value is a result indicating whether the new value was written and containing the previous value. On success this value is guaranteed to be equal to current.
+    pub fn compare_exchange(&self, current: T, new: T, _success: Ordering, _failure: Ordering) -> Result<T, T> {
+        critical_section::with(|_| unsafe {
+            let prev = *self.inner_ptr();
+            if bits_eq(&prev, &current) {
+                *self.inner_ptr() = new;
+                Ok(prev)
+            } else {
+                Err(prev)
+            }
+        })
+    }
+
+    #[inline]
+    ///Stores a value into the atomic if the current value is the same as the `current` value.
+    ///
+    ///Unlike `Atomic`, this never fails spuriously and is the same as `compare_exchange`.
+    pub fn compare_exchange_weak(&self, current: T, new: T, success: Ordering, failure: Ordering) -> Result<T, T> {
+        self.compare_exchange(current, new, success, failure)
+    }
+
+    ///Fetches the value, and applies a function to it that returns an optional new value.
+    ///Returns a `Result` of `Ok(previous_value)` if the function returned `Some(_)`, else `Err(previous_value)`.
+    ///
+    ///Note: Same as `Atomic::fetch_update`, the function may be called multiple times if the value has been changed from ISR or other threads in the meantime.
+    pub fn fetch_update<F: FnMut(T) -> Option<T>>(&self, set_order: Ordering, fetch_order: Ordering, mut cb: F) -> Result<T, T> {
+        let mut prev = self.load(fetch_order);
+        while let Some(next) = cb(prev) {
+            match self.compare_exchange_weak(prev, next, set_order, fetch_order) {
+                res @ Ok(_) => return res,
+                Err(next_prev) => prev = next_prev,
+            }
+        }
+        Err(prev)
+    }
+
+    #[inline]
+    ///Enters single critical section, within which `f` can access this and any other `CsAtomic`.
+    ///
+    ///This amortizes cost of critical section, when multiple values must be read or written at once.
+    ///As a bonus, all accesses within `f` are observed by other users of `CsAtomic` as single operation.
+    pub fn with_critical_batch<R, F: FnOnce(&CriticalAtomicAccess<'_>) -> R>(&self, f: F) -> R {
+        critical_section::with(|cs| f(&CriticalAtomicAccess { cs }))
+    }
+
+    #[inline]
+    ///Shortcut to `fetch_update` with `Relaxed` ordering for both store and load.
+    pub fn fetch_update_relaxed<F: FnMut(T) -> Option<T>>(&self, cb: F) -> Result<T, T> {
+        self.fetch_update(Ordering::Relaxed, Ordering::Relaxed, cb)
+    }
+}
+
+///Accessor to `CsAtomic`, which can only exist within critical section.
+///
+///As critical section is already entered, its methods access atomics directly, without entering critical section again.
+pub struct CriticalAtomicAccess<'cs> {
+    cs: critical_section::CriticalSection<'cs>,
+}
+
+impl<'cs> CriticalAtomicAccess<'cs> {
+    #[inline(always)]
+    ///Returns token of the critical section, within which accessor exists.
+    pub fn critical_section(&self) -> critical_section::CriticalSection<'cs> {
+        self.cs
+    }
+
+    #[inline(always)]
+    ///Loads a value from the `atomic`.
+    pub fn load<T: Copy>(&self, atomic: &CsAtomic<T>) -> T {
+        unsafe {
+            *atomic.inner_ptr()
+        }
+    }
+
+    #[inline(always)]
+    ///Stores a value into the `atomic`.
+    pub fn store<T: Copy>(&self, atomic: &CsAtomic<T>, val: T) {
+        unsafe {
+            *atomic.inner_ptr() = val;
+        }
+    }
+
+    #[inline(always)]
+    ///Stores a value into the `atomic`, returning the previous value.
+    pub fn swap<T: Copy>(&self, atomic: &CsAtomic<T>, val: T) -> T {
+        unsafe {
+            mem::replace(&mut *atomic.inner_ptr(), val)
+        }
+    }
+}
+
+macro_rules! impl_common_spec {
+    ($($ty:ident),*) => {$(
+        impl CsAtomic<$ty> {
+            /// Bitwise and with the current value, returning the previous value.
+            #[inline]
+            pub fn fetch_and(&self, val: $ty, _order: Ordering) -> $ty {
+                self.modify(|prev| prev & val)
+            }
+
+            /// Bitwise nand with the current value.
+            #[inline]
+            pub fn fetch_nand(&self, val: $ty, _order: Ordering) -> $ty {
+                self.modify(|prev| !(prev & val))
+            }
+
+            /// Bitwise or with the current value, returning the previous value.
+            #[inline]
+            pub fn fetch_or(&self, val: $ty, _order: Ordering) -> $ty {
+                self.modify(|prev| prev | val)
+            }
+
+            /// Bitwise xor with the current value, returning the previous value.
+            #[inline]
+            pub fn fetch_xor(&self, val: $ty, _order: Ordering) -> $ty {
+                self.modify(|prev| prev ^ val)
+            }
+        }
+    )*};
+}
+
+macro_rules! impl_math_spec {
+    ($($ty:ident),*) => {$(
+        impl CsAtomic<$ty> {
+            #[inline]
+            /// Minimum with the current value.
+            pub fn fetch_min(&self, val: $ty, _order: Ordering) -> $ty {
+                self.modify(|prev| core::cmp::min(prev, val))
+            }
+
+            #[inline]
+            /// Maximum with the current value.
+            pub fn fetch_max(&self, val: $ty, _order: Ordering) -> $ty {
+                self.modify(|prev| core::cmp::max(prev, val))
+            }
+
+            #[inline]
+            /// Adds to the current value, returning the previous value.
+            pub fn fetch_add(&self, val: $ty, _order: Ordering) -> $ty {
+                self.modify(|prev| prev.wrapping_add(val))
+            }
+
+            /// Subtract from the current value, returning the previous value.
+            #[inline]
+            pub fn fetch_sub(&self, val: $ty, _order: Ordering) -> $ty {
+                self.modify(|prev| prev.wrapping_sub(val))
+            }
+        }
+    )*};
+}
+
+impl_common_spec!(i8, u8, bool, i16, u16, i32, u32, i64, u64, isize, usize);
+impl_math_spec!(i8, u8, i16, u16, i32, u32, i64, u64, isize, usize);
+
+impl<T: Copy + fmt::Debug> fmt::Debug for CsAtomic<T> {
+    #[inline(always)]
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&self.load(Ordering::Relaxed), fmt)
+    }
+}
+
+impl<T> From<T> for CsAtomic<T> {
+    #[inline(always)]
+    fn from(value: T) -> Self {
+        CsAtomic::new(value)
+    }
+}
@@ -0,0 +1,193 @@
+use core::fmt;
+use core::marker::PhantomData;
+
+use crate::{Atomic, Ordering};
+
+mod sealed {
+    pub trait Sealed {}
+}
+
+///Pointer-sized word, which can be stored within `DoubleWord`.
+///
+///Implemented for pointer-sized integers and raw pointers.
+///Pointers expose their provenance when stored, so loaded pointers can be dereferenced.
+pub trait Word: Copy + sealed::Sealed {
+    #[doc(hidden)]
+    fn into_word(self) -> usize;
+    #[doc(hidden)]
+    fn from_word(word: usize) -> Self;
+}
+
+macro_rules! impl_word {
+    ($($ty:ty),*) => {$(
+        impl sealed::Sealed for $ty {}
+
+        impl Word for $ty {
+            #[inline(always)]
+            fn into_word(self) -> usize {
+                self as usize
+            }
+
+            #[inline(always)]
+            fn from_word(word: usize) -> Self {
+                word as Self
+            }
+        }
+    )*};
+}
+
+impl_word!(usize, isize, u64, i64);
+
+impl<T> sealed::Sealed for *mut T {}
+
+impl<T> Word for *mut T {
+    #[inline(always)]
+    fn into_word(self) -> usize {
+        self.expose_provenance()
+    }
+
+    #[inline(always)]
+    fn from_word(word: usize) -> Self {
+        core::ptr::with_exposed_provenance_mut(word)
+    }
+}
+
+impl<T> sealed::Sealed for *const T {}
+
+impl<T> Word for *const T {
+    #[inline(always)]
+    fn into_word(self) -> usize {
+        self.expose_provenance()
+    }
+
+    #[inline(always)]
+    fn from_word(word: usize) -> Self {
+        core::ptr::with_exposed_provenance(word)
+    }
+}
+
+///Pair of pointer-sized words, updated atomically as a whole via double-width CAS.
+///
+///Backed by `Atomic<u128>`, so it is only available on 64bit targets with 128bit atomics (e.g. `cmpxchg16b` on `x86_64`).
+///Typical use case is pointer together with counter to prevent ABA problem.
+///
+///Both `A` and `B` must implement `Word`, i.e. be pointer-sized integer or raw pointer.
+pub struct DoubleWord<A, B> {
+    inner: Atomic<u128>,
+    _words: PhantomData<(A, B)>,
+}
+
+unsafe impl<A: Send, B: Send> Sync for DoubleWord<A, B> {}
+
+impl<A: Word, B: Word> DoubleWord<A, B> {
+    #[inline(always)]
+    fn to_bits(words: (A, B)) -> u128 {
+        words.0.into_word() as u128 | ((words.1.into_word() as u128) << 64)
+    }
+
+    #[inline(always)]
+    fn from_bits(bits: u128) -> (A, B) {
+        (A::from_word(bits as usize), B::from_word((bits >> 64) as usize))
+    }
+
+    #[inline]
+    ///Creates a new instance
+    pub fn new(first: A, second: B) -> Self {
+        Self {
+            inner: Atomic::new(Self::to_bits((first, second))),
+            _words: PhantomData,
+        }
+    }
+
+    #[inline]
+    ///Loads pair.
+    ///
+    ///See `Atomic::load` for details.
+    pub fn load(&self, order: Ordering) -> (A, B) {
+        Self::from_bits(self.inner.load(order))
+    }
+
+    #[inline]
+    ///Stores pair.
+    ///
+    ///See `Atomic::store` for details.
+    pub fn store(&self, first: A, second: B, order: Ordering) {
+        self.inner.store(Self::to_bits((first, second)), order)
+    }
+
+    #[inline]
+    ///Stores `new` pair if the current pair is the same as `current`.
+    ///
+    ///Pairs are compared bitwise. See `Atomic::compare_exchange` for details.
+    pub fn compare_exchange(&self, current: (A, B), new: (A, B), success: Ordering, failure: Ordering) -> Result<(A, B), (A, B)> {
+        match self.inner.compare_exchange(Self::to_bits(current), Self::to_bits(new), success, failure) {
+            Ok(prev) => Ok(Self::from_bits(prev)),
+            Err(prev) => Err(Self::from_bits(prev)),
+        }
+    }
+
+    #[inline]
+    ///Consumes the atomic and returns the contained pair.
+    pub fn into_inner(self) -> (A, B) {
+        Self::from_bits(self.inner.into_inner())
+    }
+}
+
+impl<A: Word + fmt::Debug, B: Word + fmt::Debug> fmt::Debug for DoubleWord<A, B> {
+    #[inline(always)]
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&self.load(Ordering::Relaxed), fmt)
+    }
+}
+
+///Pointer-sized value paired with tag, which is incremented on every successful store.
+///
+///Tag makes it possible to detect ABA problem: even if value is changed back to the previously observed one, its tag differs.
+///Hence `compare_exchange` expects the whole pair, as observed by `load`, but caller provides only new value.
+pub struct Tagged<T> {
+    inner: DoubleWord<T, usize>,
+}
+
+impl<T: Word> Tagged<T> {
+    #[inline]
+    ///Creates a new instance with zero tag.
+    pub fn new(value: T) -> Self {
+        Self {
+            inner: DoubleWord::new(value, 0),
+        }
+    }
+
+    #[inline]
+    ///Loads value together with its tag.
+    pub fn load(&self, order: Ordering) -> (T, usize) {
+        self.inner.load(order)
+    }
+
+    #[inline]
+    ///Loads value without tag.
+    pub fn load_value(&self, order: Ordering) -> T {
+        self.inner.load(order).0
+    }
+
+    #[inline]
+    ///Stores `new` value if the current value and tag are the same as `current`, incrementing tag.
+    ///
+    ///`current` is expected to be the pair, returned by `load` or previous `compare_exchange`.
+    ///Returns the previous pair on success, otherwise the current pair.
+    pub fn compare_exchange(&self, current: (T, usize), new: T, success: Ordering, failure: Ordering) -> Result<(T, usize), (T, usize)> {
+        self.inner.compare_exchange(current, (new, current.1.wrapping_add(1)), success, failure)
+    }
+
+    #[inline]
+    ///Consumes the atomic and returns the contained value and tag.
+    pub fn into_inner(self) -> (T, usize) {
+        self.inner.into_inner()
+    }
+}
+
+impl<T: Word + fmt::Debug> fmt::Debug for Tagged<T> {
+    #[inline(always)]
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&self.inner, fmt)
+    }
+}
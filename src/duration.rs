@@ -0,0 +1,122 @@
+use core::fmt;
+use core::time::Duration;
+
+use crate::Ordering;
+
+#[cfg(all(feature = "atomic-128", target_has_atomic = "128"))]
+type Inner = crate::Atomic<u128>;
+#[cfg(not(all(feature = "atomic-128", target_has_atomic = "128")))]
+//Seconds and nanoseconds, the latter stored as `u64` to avoid padding
+type Inner = crate::SeqLock<[u64; 2]>;
+
+///Atomic `Duration`, stored as seconds and nanoseconds without loss of precision.
+///
+///With `atomic-128` feature on targets with 128bit atomics, it is backed by `Atomic<u128>`.
+///Otherwise it is backed by `SeqLock`, in which case `Ordering` arguments are ignored, as `SeqLock` always uses acquire/release semantics.
+pub struct AtomicDuration {
+    inner: Inner,
+}
+
+#[cfg(all(feature = "atomic-128", target_has_atomic = "128"))]
+impl AtomicDuration {
+    #[inline(always)]
+    const fn to_inner(value: Duration) -> u128 {
+        ((value.as_secs() as u128) << 32) | value.subsec_nanos() as u128
+    }
+
+    #[inline(always)]
+    const fn from_inner(value: u128) -> Duration {
+        Duration::new((value >> 32) as u64, value as u32)
+    }
+
+    #[inline]
+    ///Creates a new instance
+    pub const fn new(value: Duration) -> Self {
+        Self {
+            inner: crate::Atomic::new(Self::to_inner(value)),
+        }
+    }
+
+    #[inline]
+    ///Loads duration.
+    pub fn load(&self, order: Ordering) -> Duration {
+        Self::from_inner(self.inner.load(order))
+    }
+
+    #[inline]
+    ///Stores duration.
+    pub fn store(&self, value: Duration, order: Ordering) {
+        self.inner.store(Self::to_inner(value), order)
+    }
+
+    #[inline]
+    ///Adds `value`, returning the previous duration.
+    ///
+    ///Saturates at `Duration::MAX` instead of overflowing.
+    ///Failure ordering is derived from `order` as the strongest one allowed.
+    pub fn fetch_add(&self, value: Duration, order: Ordering) -> Duration {
+        let result = self.inner.fetch_update(order, crate::failure_ordering(order), |prev| {
+            Some(Self::to_inner(Self::from_inner(prev).saturating_add(value)))
+        });
+        match result {
+            Ok(prev) => Self::from_inner(prev),
+            Err(prev) => Self::from_inner(prev),
+        }
+    }
+}
+
+#[cfg(not(all(feature = "atomic-128", target_has_atomic = "128")))]
+impl AtomicDuration {
+    #[inline(always)]
+    const fn to_inner(value: Duration) -> [u64; 2] {
+        [value.as_secs(), value.subsec_nanos() as u64]
+    }
+
+    #[inline(always)]
+    const fn from_inner([secs, nanos]: [u64; 2]) -> Duration {
+        Duration::new(secs, nanos as u32)
+    }
+
+    #[inline]
+    ///Creates a new instance
+    pub const fn new(value: Duration) -> Self {
+        Self {
+            inner: crate::SeqLock::new(Self::to_inner(value)),
+        }
+    }
+
+    #[inline]
+    ///Loads duration.
+    pub fn load(&self, _order: Ordering) -> Duration {
+        Self::from_inner(self.inner.read())
+    }
+
+    #[inline]
+    ///Stores duration.
+    pub fn store(&self, value: Duration, _order: Ordering) {
+        self.inner.write(Self::to_inner(value))
+    }
+
+    #[inline]
+    ///Adds `value`, returning the previous duration.
+    ///
+    ///Saturates at `Duration::MAX` instead of overflowing.
+    pub fn fetch_add(&self, value: Duration, _order: Ordering) -> Duration {
+        let prev = self.inner.update(|prev| Self::to_inner(Self::from_inner(prev).saturating_add(value)));
+        Self::from_inner(prev)
+    }
+}
+
+impl Default for AtomicDuration {
+    #[inline(always)]
+    fn default() -> Self {
+        Self::new(Duration::ZERO)
+    }
+}
+
+impl fmt::Debug for AtomicDuration {
+    #[inline(always)]
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&self.load(Ordering::Relaxed), fmt)
+    }
+}
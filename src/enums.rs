@@ -0,0 +1,77 @@
+use core::fmt;
+
+use crate::{Atomic, Ordering};
+
+///Enum, which can be stored within `Atomic` with validation of its discriminant.
+///
+///Normally implemented via `#[derive(AtomicEnum)]` of `atomik-derive` crate.
+///
+///# Safety
+///
+///`Repr` must have the same size and alignment as `Self`, and `to_repr` must return discriminant of the variant (i.e. `#[repr(u8)]` enum with `Repr = u8`).
+pub unsafe trait AtomicEnum: Copy {
+    ///Integer type, which is used as representation of the enum.
+    type Repr: Copy + PartialEq;
+
+    ///Returns discriminant of the variant.
+    fn to_repr(self) -> Self::Repr;
+    ///Returns variant with `repr` discriminant, if any.
+    fn from_repr(repr: Self::Repr) -> Option<Self>;
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+///Error indicating that atomic contained bit pattern, which is not a valid discriminant of the enum.
+pub struct InvalidDiscriminant<R>(pub R);
+
+impl<R: fmt::Debug> fmt::Display for InvalidDiscriminant<R> {
+    #[inline]
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(fmt, "Invalid enum discriminant {:?}", self.0)
+    }
+}
+
+impl<T: AtomicEnum> Atomic<T> {
+    #[inline(always)]
+    fn as_repr(&self) -> &Atomic<T::Repr> {
+        //AtomicEnum guarantees that Repr has the same layout
+        unsafe {
+            &*(self as *const Self as *const Atomic<T::Repr>)
+        }
+    }
+
+    #[inline(always)]
+    fn to_enum(repr: T::Repr) -> Result<T, InvalidDiscriminant<T::Repr>> {
+        T::from_repr(repr).ok_or(InvalidDiscriminant(repr))
+    }
+
+    #[inline]
+    ///Loads enum, validating its discriminant.
+    ///
+    ///Returns error if stored bit pattern is not a valid variant.
+    pub fn load_enum(&self, order: Ordering) -> Result<T, InvalidDiscriminant<T::Repr>> {
+        Self::to_enum(self.as_repr().load(order))
+    }
+
+    #[inline]
+    ///Stores enum.
+    pub fn store_enum(&self, val: T, order: Ordering) {
+        self.as_repr().store(val.to_repr(), order)
+    }
+
+    #[inline]
+    ///Stores enum, returning the previous one after validating its discriminant.
+    pub fn swap_enum(&self, val: T, order: Ordering) -> Result<T, InvalidDiscriminant<T::Repr>> {
+        Self::to_enum(self.as_repr().swap(val.to_repr(), order))
+    }
+
+    #[inline]
+    ///Stores `new` enum if the current one is `current`.
+    ///
+    ///Same as `compare_exchange`, but returns error if the current value is not a valid variant.
+    pub fn compare_exchange_enum(&self, current: T, new: T, success: Ordering, failure: Ordering) -> Result<Result<T, T>, InvalidDiscriminant<T::Repr>> {
+        match self.as_repr().compare_exchange(current.to_repr(), new.to_repr(), success, failure) {
+            Ok(prev) => Self::to_enum(prev).map(Ok),
+            Err(prev) => Self::to_enum(prev).map(Err),
+        }
+    }
+}
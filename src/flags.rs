@@ -0,0 +1,92 @@
+use core::fmt;
+
+use crate::{Atomic, Ordering};
+
+#[repr(transparent)]
+///Set of 64 flags packed into single atomic word.
+///
+///Each method operates on single bit, identified by `idx`, which must be less than 64.
+pub struct Flags64 {
+    inner: Atomic<u64>,
+}
+
+impl Flags64 {
+    ///Number of flags within set.
+    pub const CAPACITY: usize = 64;
+
+    #[inline]
+    ///Creates a new instance with initial `bits`.
+    pub const fn new(bits: u64) -> Self {
+        Self {
+            inner: Atomic::new(bits),
+        }
+    }
+
+    #[inline(always)]
+    fn mask(idx: usize) -> u64 {
+        assert!(idx < Self::CAPACITY, "Flags64: index out of bounds");
+        1 << idx
+    }
+
+    #[inline]
+    ///Sets flag, returning its previous state.
+    ///
+    ///Panics if `idx` is out of bounds.
+    pub fn set(&self, idx: usize, order: Ordering) -> bool {
+        let mask = Self::mask(idx);
+        self.inner.fetch_or(mask, order) & mask != 0
+    }
+
+    #[inline]
+    ///Clears flag, returning its previous state.
+    ///
+    ///Panics if `idx` is out of bounds.
+    pub fn clear(&self, idx: usize, order: Ordering) -> bool {
+        let mask = Self::mask(idx);
+        self.inner.fetch_and(!mask, order) & mask != 0
+    }
+
+    #[inline]
+    ///Toggles flag, returning its previous state.
+    ///
+    ///Panics if `idx` is out of bounds.
+    pub fn toggle(&self, idx: usize, order: Ordering) -> bool {
+        let mask = Self::mask(idx);
+        self.inner.fetch_xor(mask, order) & mask != 0
+    }
+
+    #[inline]
+    ///Loads state of the flag.
+    ///
+    ///Panics if `idx` is out of bounds.
+    pub fn get(&self, idx: usize, order: Ordering) -> bool {
+        let mask = Self::mask(idx);
+        self.inner.load(order) & mask != 0
+    }
+
+    #[inline]
+    ///Loads all flags as bits.
+    pub fn bits(&self, order: Ordering) -> u64 {
+        self.inner.load(order)
+    }
+
+    #[inline]
+    ///Consumes set and returns all flags as bits.
+    pub fn into_bits(self) -> u64 {
+        self.inner.into_inner()
+    }
+}
+
+impl Default for Flags64 {
+    #[inline(always)]
+    fn default() -> Self {
+        Self::new(0)
+    }
+}
+
+impl fmt::Debug for Flags64 {
+    #[inline(always)]
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(fmt, "Flags64({:#066b})", self.bits(Ordering::Relaxed))
+    }
+}
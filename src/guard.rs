@@ -0,0 +1,120 @@
+use core::marker::PhantomData;
+
+use crate::{Atomic, Ordering};
+
+#[cfg(all(feature = "std", debug_assertions))]
+mod checks {
+    use std::sync::Mutex;
+    use std::thread::{self, ThreadId};
+    use std::vec::Vec;
+
+    //Address of atomic, thread holding guards and number of guards
+    static OWNERS: Mutex<Vec<(usize, ThreadId, usize)>> = Mutex::new(Vec::new());
+
+    pub fn acquire(addr: usize) {
+        let current = thread::current().id();
+        let conflict = {
+            let mut owners = OWNERS.lock().unwrap_or_else(|error| error.into_inner());
+            match owners.iter_mut().find(|(owner_addr, _, _)| *owner_addr == addr) {
+                Some((_, owner, count)) if *owner == current => {
+                    *count += 1;
+                    false
+                },
+                Some(_) => true,
+                None => {
+                    owners.push((addr, current, 1));
+                    false
+                }
+            }
+        };
+
+        if conflict {
+            panic!("SingleThreadGuard: atomic at {:#x} is already guarded by another thread", addr);
+        }
+    }
+
+    pub fn release(addr: usize) {
+        let mut owners = OWNERS.lock().unwrap_or_else(|error| error.into_inner());
+        if let Some(idx) = owners.iter().position(|(owner_addr, _, _)| *owner_addr == addr) {
+            owners[idx].2 -= 1;
+            if owners[idx].2 == 0 {
+                owners.swap_remove(idx);
+            }
+        }
+    }
+
+    pub fn verify(addr: usize) {
+        let current = thread::current().id();
+        let is_owner = {
+            let owners = OWNERS.lock().unwrap_or_else(|error| error.into_inner());
+            owners.iter().any(|(owner_addr, owner, _)| *owner_addr == addr && *owner == current)
+        };
+        assert!(is_owner, "SingleThreadGuard: atomic at {:#x} is accessed from another thread", addr);
+    }
+}
+
+///Guard asserting that atomic is accessed by single thread, while guard is alive.
+///
+///Created via `Atomic::single_thread_guard`, which panics if another thread already holds guard of the same atomic.
+///Access through the guard also verifies that it happens on the thread, which created guard.
+///
+///Checks are performed only in debug builds with `std` feature, otherwise guard is a plain wrapper.
+///Note that access to atomic bypassing guard cannot be detected.
+pub struct SingleThreadGuard<'a, T> {
+    atomic: &'a Atomic<T>,
+    //Guard is bound to the thread that created it
+    _thread: PhantomData<*const ()>,
+}
+
+impl<'a, T: Copy> SingleThreadGuard<'a, T> {
+    #[inline(always)]
+    fn verify(&self) {
+        #[cfg(all(feature = "std", debug_assertions))]
+        checks::verify(self.atomic as *const Atomic<T> as usize);
+    }
+
+    #[inline]
+    ///Loads a value from the atomic.
+    pub fn load(&self, order: Ordering) -> T {
+        self.verify();
+        self.atomic.load(order)
+    }
+
+    #[inline]
+    ///Stores a value into the atomic.
+    pub fn store(&self, val: T, order: Ordering) {
+        self.verify();
+        self.atomic.store(val, order)
+    }
+
+    #[inline]
+    ///Stores a value into the atomic, returning the previous value.
+    pub fn swap(&self, val: T, order: Ordering) -> T {
+        self.verify();
+        self.atomic.swap(val, order)
+    }
+}
+
+impl<T> Drop for SingleThreadGuard<'_, T> {
+    #[inline]
+    fn drop(&mut self) {
+        #[cfg(all(feature = "std", debug_assertions))]
+        checks::release(self.atomic as *const Atomic<T> as usize);
+    }
+}
+
+impl<T: Copy> Atomic<T> {
+    #[inline]
+    ///Creates guard, asserting that atomic is accessed only by the current thread while guard is alive.
+    ///
+    ///In debug builds with `std` feature, panics if another thread holds guard of this atomic.
+    pub fn single_thread_guard(&self) -> SingleThreadGuard<'_, T> {
+        #[cfg(all(feature = "std", debug_assertions))]
+        checks::acquire(self as *const Self as usize);
+
+        SingleThreadGuard {
+            atomic: self,
+            _thread: PhantomData,
+        }
+    }
+}
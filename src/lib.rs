@@ -11,10 +11,26 @@
 //!## Features
 //!
 //!- `critical-section-polyfill` - Enables polyfill implementation for embedded targets based on critical section. This polyfill only valid for single threaded chips.
+//!- `critical-section` - Enables `CsAtomic` which always relies on critical section, regardless of backend. User must provide `critical-section` implementation.
+//!- `defmt` - Implements `defmt::Format` for `Atomic`.
+//!- `arc` - Enables `AtomicArc`, which allows to atomically replace `Arc`. Requires `alloc`.
+//!- `async` - Enables `AtomicFlag`, which can be awaited until it is set, and `async_fetch_update`, which computes new value using async closure.
+//!- `debug-checks` - Enables diagnostic methods to help debugging lock-free algorithms. Together with `std`, debug builds also detect overlapping atomics of different width, which are alive at the same time after creation via `from_bytes_mut`.
+//!- `inline-asm` - Enables `Atomic<u64>::fetch_add_asm`, which uses inline assembly on `x86_64`.
+//!- `nt-store` - Enables `store_nt` for 32bit and 64bit integers, which uses non-temporal stores on x86.
+//!- `registry` - Enables global registry of atomics, created via `Atomic::new_registered`, for debug tooling. Implies `std`.
+//!- `std` - Enables methods that rely on `std`.
+//!- `atomic-128` - Enables 128bit atomics on targets with `target_has_atomic = "128"`. Requires nightly compiler, as `AtomicU128` is unstable. These always use `core` atomics, regardless of backend.
+//!- `strict-test` - Forces every atomic operation to use `SeqCst` ordering, regardless of requested one. Intended for tests only, to tell apart ordering bugs from logic bugs.
 
 #![no_std]
 #![warn(missing_docs)]
-#![cfg_attr(feature = "cargo-clippy", allow(clippy::style))]
+#![cfg_attr(all(feature = "atomic-128", target_has_atomic = "128"), feature(integer_atomics))]
+
+#[cfg(feature = "std")]
+extern crate std;
+#[cfg(feature = "arc")]
+extern crate alloc;
 
 use core::{fmt, mem};
 use core::cell::UnsafeCell;
@@ -25,6 +41,90 @@ use core::sync::atomic;
 pub use core::sync::atomic::Ordering;
 
 mod ops;
+mod enums;
+pub use enums::{AtomicEnum, InvalidDiscriminant};
+mod transparent;
+pub use transparent::{AtomicTransparent, AtomicInteger};
+mod pack;
+pub use pack::{AtomicPack, AtomicPacked};
+mod update;
+pub use update::Update;
+mod minmax;
+pub use minmax::MinMax;
+mod option;
+mod guard;
+pub use guard::SingleThreadGuard;
+mod bytes;
+pub use bytes::AtomicBytesRef;
+#[cfg(feature = "registry")]
+mod registry;
+#[cfg(feature = "registry")]
+pub use registry::{dump_registry, write_registry};
+#[cfg(all(feature = "debug-checks", feature = "std"))]
+mod alias;
+#[cfg(all(feature = "debug-checks", feature = "std"))]
+pub use alias::clear_alias_registry;
+#[cfg(any(feature = "critical-section-polyfill", target_has_atomic = "64"))]
+mod pair;
+#[cfg(any(feature = "critical-section-polyfill", target_has_atomic = "64"))]
+pub use pair::Pair32;
+#[cfg(any(feature = "critical-section-polyfill", target_has_atomic = "64"))]
+mod flags;
+#[cfg(any(feature = "critical-section-polyfill", target_has_atomic = "64"))]
+pub use flags::Flags64;
+#[cfg(any(feature = "critical-section-polyfill", target_has_atomic = "64"))]
+mod meter;
+#[cfg(any(feature = "critical-section-polyfill", target_has_atomic = "64"))]
+pub use meter::ContentionMeter;
+#[cfg(any(feature = "critical-section-polyfill", target_has_atomic = "64"))]
+mod sharded;
+#[cfg(any(feature = "critical-section-polyfill", target_has_atomic = "64"))]
+pub use sharded::ShardedCounter;
+#[cfg(any(feature = "critical-section-polyfill", target_has_atomic = "64"))]
+mod sequenced;
+#[cfg(any(feature = "critical-section-polyfill", target_has_atomic = "64"))]
+pub use sequenced::SequencedAtomic;
+#[cfg(all(feature = "atomic-128", target_has_atomic = "128", target_pointer_width = "64"))]
+mod double;
+#[cfg(all(feature = "atomic-128", target_has_atomic = "128", target_pointer_width = "64"))]
+pub use double::{DoubleWord, Tagged, Word};
+#[cfg(any(feature = "critical-section-polyfill", all(target_has_atomic = "8", target_has_atomic = "32")))]
+mod seqlock;
+#[cfg(any(feature = "critical-section-polyfill", all(target_has_atomic = "8", target_has_atomic = "32")))]
+pub use seqlock::{NoPadding, SeqLock};
+#[cfg(any(feature = "critical-section-polyfill", all(target_has_atomic = "8", target_has_atomic = "32")))]
+mod duration;
+#[cfg(any(feature = "critical-section-polyfill", all(target_has_atomic = "8", target_has_atomic = "32")))]
+pub use duration::AtomicDuration;
+#[cfg(any(feature = "critical-section-polyfill", target_has_atomic = "32"))]
+mod versioned;
+#[cfg(any(feature = "critical-section-polyfill", target_has_atomic = "32"))]
+pub use versioned::Versioned;
+mod slice;
+#[cfg(any(feature = "critical-section-polyfill", target_has_atomic = "32"))]
+pub use slice::{xor_fold, record};
+#[cfg(all(feature = "inline-asm", any(feature = "critical-section-polyfill", target_has_atomic = "64")))]
+mod asm;
+#[cfg(feature = "nt-store")]
+mod nt;
+#[cfg(any(feature = "critical-section-polyfill", target_has_atomic = "ptr"))]
+mod ptr;
+#[cfg(all(feature = "arc", any(feature = "critical-section-polyfill", target_has_atomic = "ptr")))]
+mod arc;
+#[cfg(all(feature = "arc", any(feature = "critical-section-polyfill", target_has_atomic = "ptr")))]
+pub use arc::AtomicArc;
+#[cfg(any(feature = "critical-section-polyfill", target_has_atomic = "32"))]
+mod net;
+#[cfg(any(feature = "critical-section-polyfill", target_has_atomic = "32"))]
+pub use net::AtomicIpv4;
+#[cfg(all(feature = "async", any(feature = "critical-section-polyfill", target_has_atomic = "8")))]
+mod notify;
+#[cfg(all(feature = "async", any(feature = "critical-section-polyfill", target_has_atomic = "8")))]
+pub use notify::{AtomicFlag, Wait};
+#[cfg(feature = "critical-section")]
+mod cs;
+#[cfg(feature = "critical-section")]
+pub use cs::{CsAtomic, CriticalAtomicAccess};
 
 #[repr(transparent)]
 ///Generic atomic which allows any `T` to be used as lock-free atomic integer.
@@ -35,6 +135,13 @@ mod ops;
 ///
 ///`fetch_*` makes sense only to integers, hence they are implemented as specialized methods.
 ///
+///Primitive integers and `bool` go through the same generic `load`/`store`/`swap`/`compare_exchange`.
+///Dedicated inherent methods for them cannot be added on stable Rust, as they would conflict with generic ones (E0592)
+///and selecting implementation by exact type requires unstable `specialization`.
+///
+///Values are compared bitwise (e.g. in `compare_exchange`), which is notable for floats:
+///`NaN` is equal to `NaN` with the same bits, while `0.0` and `-0.0` are different.
+///
 ///All methods require atomic support, if target has no atomics of required size, then it will fail to compile using particular methods.
 pub struct Atomic<T> {
     inner: UnsafeCell<T>
@@ -50,6 +157,63 @@ impl<T: Default> Default for Atomic<T> {
     }
 }
 
+#[inline(always)]
+//Returns ordering, which is actually used by atomic operations when `order` is requested.
+//
+//This is always `order` itself, unless `strict-test` feature is enabled, in which case it is `SeqCst`.
+pub(crate) const fn effective_ordering(order: Ordering) -> Ordering {
+    if cfg!(feature = "strict-test") {
+        Ordering::SeqCst
+    } else {
+        order
+    }
+}
+
+//Returns the strongest failure ordering allowed to be used with `success` ordering.
+#[inline(always)]
+const fn failure_ordering(success: Ordering) -> Ordering {
+    match success {
+        Ordering::Release | Ordering::Relaxed => Ordering::Relaxed,
+        Ordering::Acquire | Ordering::AcqRel => Ordering::Acquire,
+        _ => Ordering::SeqCst,
+    }
+}
+
+//Reinterprets value as unsigned integer of the same size.
+//Atomic already requires `T` to be of the same size as one of integers.
+#[inline(always)]
+fn to_bits<T>(value: &T) -> u64 {
+    unsafe {
+        match mem::size_of::<T>() {
+            1 => mem::transmute_copy::<T, u8>(value) as u64,
+            2 => mem::transmute_copy::<T, u16>(value) as u64,
+            4 => mem::transmute_copy::<T, u32>(value) as u64,
+            8 => mem::transmute_copy::<T, u64>(value),
+            #[cfg(all(feature = "atomic-128", target_has_atomic = "128"))]
+            16 => {
+                let value = mem::transmute_copy::<T, u128>(value);
+                (value ^ (value >> 64)) as u64
+            },
+            _ => unreachable!(),
+        }
+    }
+}
+
+//Compares values bitwise, same as atomic operations do.
+fn bits_eq<T>(left: &T, right: &T) -> bool {
+    unsafe {
+        match mem::size_of::<T>() {
+            1 => mem::transmute_copy::<T, u8>(left) == mem::transmute_copy::<T, u8>(right),
+            2 => mem::transmute_copy::<T, u16>(left) == mem::transmute_copy::<T, u16>(right),
+            4 => mem::transmute_copy::<T, u32>(left) == mem::transmute_copy::<T, u32>(right),
+            8 => mem::transmute_copy::<T, u64>(left) == mem::transmute_copy::<T, u64>(right),
+            #[cfg(all(feature = "atomic-128", target_has_atomic = "128"))]
+            16 => mem::transmute_copy::<T, u128>(left) == mem::transmute_copy::<T, u128>(right),
+            _ => unreachable!(),
+        }
+    }
+}
+
 macro_rules! match_atomic_size {
     ($SIZE:expr => $fn:ident on $T:ident) => {
         match $SIZE {
@@ -61,11 +225,33 @@ macro_rules! match_atomic_size {
             4 if mem::align_of::<$T>() >= mem::align_of::<u32>() => ops::u32::$fn,
             #[cfg(any(feature = "critical-section-polyfill", target_has_atomic = "64"))]
             8 if mem::align_of::<$T>() >= mem::align_of::<u64>() => ops::u64::$fn,
+            #[cfg(all(feature = "atomic-128", target_has_atomic = "128"))]
+            16 if mem::align_of::<$T>() >= mem::align_of::<u128>() => ops::u128::$fn,
             _ => unimplemented!(),
         }
     };
 }
 
+//Pointer sized `T` might be pointer, in which case it goes through `AtomicPtr` to preserve provenance.
+macro_rules! match_pointer_size {
+    ($fn:ident on $T:ident) => {
+        match mem::size_of::<$T>() == mem::size_of::<usize>() && mem::align_of::<$T>() >= mem::align_of::<usize>() {
+            #[cfg(any(feature = "critical-section-polyfill", target_has_atomic = "ptr"))]
+            true => Some(ops::ptr::$fn),
+            _ => None,
+        }
+    };
+}
+
+//Primitive numbers never carry provenance, so they always use integer atomic.
+//Name is used as there is no other way to tell them apart from pointers of the same size on stable.
+#[inline(always)]
+fn is_number<T>() -> bool {
+    matches!(core::any::type_name::<T>(), "u16" | "i16" | "u32" | "i32" | "f32" | "char" | "u64" | "i64" | "f64" | "usize" | "isize")
+}
+
+type CmpExFn<T> = fn(*mut T, T, T, Ordering, Ordering) -> Result<T, T>;
+
 impl<T> Atomic<T> {
     //For this to affect compilation, this constant must be used
     //hence we slap assert in `new` which is only way to legit create atomic;
@@ -84,28 +270,180 @@ impl<T> Atomic<T> {
     const SWAP: fn(*mut T, T, Ordering) -> T = {
         match_atomic_size!(Self::TYPE_SIZE => atomic_swap on T)
     };
-    const CMP_EX: fn(*mut T, T, T, Ordering, Ordering) -> Result<T, T> = {
+    const CMP_EX: CmpExFn<T> = {
         match_atomic_size!(Self::TYPE_SIZE => atomic_compare_exchange on T)
     };
-    const CMP_EX_WEAK: fn(*mut T, T, T, Ordering, Ordering) -> Result<T, T> = {
+    const CMP_EX_WEAK: CmpExFn<T> = {
         match_atomic_size!(Self::TYPE_SIZE => atomic_compare_exchange_weak on T)
     };
 
+    const PTR_LOAD: Option<fn(*mut T, Ordering) -> T> = match_pointer_size!(atomic_load on T);
+    const PTR_STORE: Option<fn(*mut T, T, Ordering)> = match_pointer_size!(atomic_store on T);
+    const PTR_SWAP: Option<fn(*mut T, T, Ordering) -> T> = match_pointer_size!(atomic_swap on T);
+    const PTR_CMP_EX: Option<CmpExFn<T>> = match_pointer_size!(atomic_compare_exchange on T);
+    const PTR_CMP_EX_WEAK: Option<CmpExFn<T>> = match_pointer_size!(atomic_compare_exchange_weak on T);
+
+    #[inline(always)]
+    fn select_op<F>(ptr_op: Option<F>, op: F) -> F {
+        match ptr_op {
+            Some(ptr_op) if !is_number::<T>() => ptr_op,
+            _ => op,
+        }
+    }
+
+    //Referenced by `new`, hence it is evaluated for every `T` used to construct atomic.
+    const IS_VALID: () = assert!(
+        match Self::TYPE_SIZE {
+            1 => mem::align_of::<T>() >= mem::align_of::<u8>(),
+            2 => mem::align_of::<T>() >= mem::align_of::<u16>(),
+            4 => mem::align_of::<T>() >= mem::align_of::<u32>(),
+            8 => mem::align_of::<T>() >= mem::align_of::<u64>(),
+            #[cfg(all(feature = "atomic-128", target_has_atomic = "128"))]
+            16 => mem::align_of::<T>() >= mem::align_of::<u128>(),
+            _ => false,
+        },
+        "Atomic<T>: T must have size and alignment of u8, u16, u32, u64 or, with atomic-128 feature, u128"
+    );
+
     #[inline]
     ///Creates a new instance
+    ///
+    ///Fails to compile if `T` has size or alignment incompatible with atomic integers:
+    ///
+    ///```compile_fail
+    ///let _ = atomik::Atomic::new([0u8; 4]);
+    ///```
+    ///
+    ///```compile_fail
+    ///let _ = atomik::Atomic::new([0u16; 3]);
+    ///```
     pub const fn new(value: T) -> Atomic<T> {
-        debug_assert!(Self::TYPE_SIZE <= mem::size_of::<u64>());
+        let () = Self::IS_VALID;
 
         Atomic {
             inner: UnsafeCell::new(value),
         }
     }
+
+    #[inline]
+    ///Creates a new instance, asserting that `T` has size of `W` bytes.
+    ///
+    ///Intended for generic code, that expects particular width of the atomic.
+    ///If size of `T` is not `W`, then it fails to compile:
+    ///
+    ///```compile_fail
+    ///let _ = atomik::Atomic::new_with_width::<4>(0u64);
+    ///```
+    pub const fn new_with_width<const W: usize>(value: T) -> Atomic<T> {
+        let () = WidthCheck::<T, W>::IS_VALID;
+        Self::new(value)
+    }
+
+    #[inline]
+    ///Creates a new instance, asserting that `T` is always lock-free.
+    ///
+    ///Intended for real-time code, which must not silently fall back to critical section of `critical-section-polyfill`.
+    ///If `T` is not lock-free on target, then it fails to compile:
+    ///
+    ///```compile_fail
+    ///let _ = atomik::Atomic::new_lock_free([0u64; 2]);
+    ///```
+    pub const fn new_lock_free(value: T) -> Atomic<T> {
+        let () = LockFreeCheck::<T>::IS_VALID;
+        Self::new(value)
+    }
+
+    #[inline]
+    ///Returns whether operations on `Atomic<T>` are always lock-free on target.
+    ///
+    ///Returns `false` when `T` is implemented via critical section of `critical-section-polyfill`,
+    ///or when `T` has no matching atomic integer at all.
+    pub const fn is_always_lock_free() -> bool {
+        match mem::size_of::<T>() {
+            1 => cfg!(target_has_atomic = "8") && mem::align_of::<T>() >= mem::align_of::<u8>(),
+            2 => cfg!(target_has_atomic = "16") && mem::align_of::<T>() >= mem::align_of::<u16>(),
+            4 => cfg!(target_has_atomic = "32") && mem::align_of::<T>() >= mem::align_of::<u32>(),
+            8 => cfg!(target_has_atomic = "64") && mem::align_of::<T>() >= mem::align_of::<u64>(),
+            #[cfg(all(feature = "atomic-128", target_has_atomic = "128"))]
+            16 => mem::align_of::<T>() >= mem::align_of::<u128>(),
+            _ => false,
+        }
+    }
+}
+
+struct LockFreeCheck<T>(core::marker::PhantomData<T>);
+
+impl<T> LockFreeCheck<T> {
+    const IS_VALID: () = assert!(Atomic::<T>::is_always_lock_free(), "Atomic<T>: T is not lock-free on this target");
+}
+
+#[macro_export]
+///Creates `Atomic` initialized with result of `const` computation.
+///
+///Initializer is always evaluated at compile time, even outside of `static`.
+///This is alternative to accepting `const` closure, which is not possible until `const_trait_impl` is stable.
+///
+///```
+///const fn compute() -> u32 {
+///    6 * 7
+///}
+///
+///static ANSWER: atomik::Atomic<u32> = atomik::atomic_const!(u32 => compute());
+///assert_eq!(ANSWER.load(atomik::Ordering::Relaxed), 42);
+///```
+macro_rules! atomic_const {
+    ($ty:ty => $init:expr) => {{
+        const INIT: $ty = $init;
+        $crate::Atomic::<$ty>::new(INIT)
+    }};
+}
+
+struct WidthCheck<T, const W: usize>(core::marker::PhantomData<T>);
+
+impl<T, const W: usize> WidthCheck<T, W> {
+    const IS_VALID: () = assert!(mem::size_of::<T>() == W, "Atomic<T>: size of T doesn't match expected width W");
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+///Outcome of `compare_exchange_outcome`
+pub enum CasOutcome<T> {
+    ///New value is stored, contains the previous value, which is equal to `current`
+    Won(T),
+    ///Exchange failed, contains the actual value
+    Lost(T),
+}
+
+impl<T> CasOutcome<T> {
+    #[inline]
+    ///Returns whether new value is stored.
+    pub fn is_won(&self) -> bool {
+        matches!(self, CasOutcome::Won(_))
+    }
+
+    #[inline]
+    ///Converts into `Result`, as returned by `compare_exchange`.
+    pub fn into_result(self) -> Result<T, T> {
+        match self {
+            CasOutcome::Won(prev) => Ok(prev),
+            CasOutcome::Lost(actual) => Err(actual),
+        }
+    }
+}
+
+impl<T> From<Result<T, T>> for CasOutcome<T> {
+    #[inline(always)]
+    fn from(result: Result<T, T>) -> Self {
+        match result {
+            Ok(prev) => CasOutcome::Won(prev),
+            Err(actual) => CasOutcome::Lost(actual),
+        }
+    }
 }
 
 impl<T: Copy> Atomic<T> {
     #[inline]
     fn inner_ptr(&self) -> *mut T {
-        self.inner.get() as *mut T
+        self.inner.get()
     }
 
     ///Returns a mutable reference to the underlying type.
@@ -124,6 +462,14 @@ impl<T: Copy> Atomic<T> {
         self.inner.into_inner()
     }
 
+    ///Consumes the atomic and passes the contained value to `f`, returning its result.
+    ///
+    ///Shortcut to `f(atomic.into_inner())`, useful for teardown.
+    #[inline]
+    pub fn consume_with<R, F: FnOnce(T) -> R>(self, f: F) -> R {
+        f(self.into_inner())
+    }
+
     ///Loads a value from the atomic integer.
     ///
     ///load takes an Ordering argument which describes the memory ordering of this operation.
@@ -134,7 +480,7 @@ impl<T: Copy> Atomic<T> {
     ///Panics if `order` is `Release` or `AcqRel`.
     #[inline]
     pub fn load(&self, order: Ordering) -> T {
-        Self::LOAD(self.inner_ptr(), order)
+        Self::select_op(Self::PTR_LOAD, Self::LOAD)(self.inner_ptr(), order)
     }
 
     ///Stores a value into the atomic integer.
@@ -146,7 +492,84 @@ impl<T: Copy> Atomic<T> {
     ///
     ///Panics if `order` is `Acquire` or `AcqRel`.
     pub fn store(&self, val: T, order: Ordering) {
-        Self::STORE(self.inner_ptr(), val, order)
+        Self::select_op(Self::PTR_STORE, Self::STORE)(self.inner_ptr(), val, order)
+    }
+
+    #[inline]
+    ///Publishes value for readers, using `Release` store.
+    ///
+    ///Pairs with `consume`: reader that observes published value via `consume` is guaranteed to see all writes made before `publish`.
+    pub fn publish(&self, val: T) {
+        self.store(val, Ordering::Release)
+    }
+
+    #[inline]
+    ///Loads value published by `publish`, using `Acquire` load.
+    ///
+    ///Once published value is observed, all writes made by publisher prior to `publish` are visible to the caller.
+    pub fn consume(&self) -> T {
+        self.load(Ordering::Acquire)
+    }
+
+    #[inline]
+    ///Loads a value from the atomic into `out`.
+    ///
+    ///Same as `*out = atomic.load(order)`, which can be more natural when `out` already exists.
+    pub fn load_into(&self, out: &mut T, order: Ordering) {
+        *out = self.load(order);
+    }
+
+    #[inline]
+    ///Loads a value with `Acquire` ordering.
+    ///
+    ///Pairs with `store_release`, same as `consume` pairs with `publish`.
+    pub fn load_acquire(&self) -> T {
+        self.load(Ordering::Acquire)
+    }
+
+    #[inline]
+    ///Stores a value with `Release` ordering.
+    ///
+    ///Pairs with `load_acquire`, same as `publish` pairs with `consume`.
+    pub fn store_release(&self, val: T) {
+        self.store(val, Ordering::Release)
+    }
+
+    #[inline]
+    ///Stores a value, verifying in debug mode that it has been stored.
+    ///
+    ///In debug mode value is loaded back right after store and compared bitwise, panicking on mismatch.
+    ///This helps to catch bugs in backend or aliasing, but note that concurrent store from other thread also causes mismatch.
+    ///In release mode it is the same as `store`.
+    pub fn store_verified(&self, val: T, order: Ordering) {
+        self.store(val, order);
+        //Coherence guarantees that the same thread observes own store, so relaxed load is enough
+        debug_assert!(bits_eq(&self.load(Ordering::Relaxed), &val), "Atomic: stored value is not observed by subsequent load");
+    }
+
+    #[inline]
+    ///Loads a value through pinned reference.
+    ///
+    ///Atomic never moves its storage, so it is always safe to access it through `Pin`.
+    ///Useful when atomic is field of `!Unpin` self-referential structure.
+    pub fn load_pinned(self: core::pin::Pin<&Self>, order: Ordering) -> T {
+        self.get_ref().load(order)
+    }
+
+    #[inline]
+    ///Stores a value through pinned reference.
+    ///
+    ///Atomic never moves its storage, so it is always safe to access it through `Pin`.
+    pub fn store_pinned(self: core::pin::Pin<&Self>, val: T, order: Ordering) {
+        self.get_ref().store(val, order)
+    }
+
+    #[inline]
+    ///Loads value and passes it to `f`.
+    ///
+    ///Same as `f(atomic.load(order))`, expressing intent to observe value (e.g. for logging or metrics).
+    pub fn inspect<F: FnOnce(T)>(&self, order: Ordering, f: F) {
+        f(self.load(order))
     }
 
     #[inline]
@@ -155,8 +578,44 @@ impl<T: Copy> Atomic<T> {
     ///`swap` takes an `Ordering` argument which describes the memory ordering of this operation.
     ///All ordering modes are possible.
     ///Note that using `Acquire` makes the store part of this operation `Relaxed`, and using `Release` makes the load part `Relaxed`.
+    ///Using `AcqRel` makes load part `Acquire` and store part `Release`, hence the whole operation both acquires previous value and releases new one.
+    ///
+    ///Prefer `store` when previous value is not needed, as plain store is cheaper than read-modify-write on most targets.
     pub fn swap(&self, val: T, order: Ordering) -> T {
-        Self::SWAP(self.inner_ptr(), val, order)
+        Self::select_op(Self::PTR_SWAP, Self::SWAP)(self.inner_ptr(), val, order)
+    }
+
+    #[inline]
+    ///Stores a value into the atomic, returning the previous value.
+    ///
+    ///This is alias of `swap`, named to express intent of storing value, when the previous one is also needed.
+    pub fn store_return_prev(&self, val: T, order: Ordering) -> T {
+        self.swap(val, order)
+    }
+
+    #[inline]
+    ///Stores a value into the atomic, passing the previous value to `cleanup`.
+    ///
+    ///This binds displaced value to its cleanup (e.g. decrementing reference count), transferring ownership of it to `cleanup`.
+    pub fn swap_cleanup<F: FnOnce(T)>(&self, val: T, order: Ordering, cleanup: F) {
+        cleanup(self.swap(val, order))
+    }
+
+    #[inline]
+    ///Stores a value into the atomic, returning the previous value together with id produced by `make_id`.
+    ///
+    ///`make_id` is called exactly once, right after swap, hence id is always minted after new value has been handed over.
+    pub fn swap_with_id<F: FnOnce() -> u64>(&self, val: T, make_id: F, order: Ordering) -> (T, u64) {
+        let prev = self.swap(val, order);
+        (prev, make_id())
+    }
+
+    #[inline]
+    ///Stores a value into the atomic, returning the previous value, using `AcqRel` ordering.
+    ///
+    ///Suitable to hand over ownership: caller observes all writes made before previous value was stored, while writes made before this call are visible to whoever takes new value.
+    pub fn exchange(&self, val: T) -> T {
+        self.swap(val, Ordering::AcqRel)
     }
 
     ///Stores a value into the atomic if the current value is the same as the `current` value.
@@ -166,7 +625,7 @@ impl<T: Copy> Atomic<T> {
     ///`compare_exchange` takes two `Ordering` arguments to describe the memory ordering of this operation. `success` describes the required ordering for the read-modify-write operation that takes place if the comparison with `current` succeeds. `failure` describes the required ordering for the load operation that takes place when the comparison fails. Using `Acquire` as success ordering makes the store part of this operation `Relaxed`, and using `Release` makes the successful load `Relaxed`. The `failure` ordering can only be `SeqCst`, `Acquire` or `Relaxed`.
     #[inline]
     pub fn compare_exchange(&self, current: T, new: T, success: Ordering, failure: Ordering) -> Result<T, T> {
-        Self::CMP_EX(self.inner_ptr(), current, new, success, failure)
+        Self::select_op(Self::PTR_CMP_EX, Self::CMP_EX)(self.inner_ptr(), current, new, success, failure)
     }
 
     #[inline]
@@ -176,11 +635,27 @@ impl<T: Copy> Atomic<T> {
     ///
     ///`compare_exchange_weak` takes two `Ordering` arguments to describe the memory ordering of this operation. `success` describes the required ordering for the read-modify-write operation that takes place if the comparison with `current` succeeds. `failure` describes the required ordering for the load operation that takes place when the comparison fails. Using `Acquire` as success ordering makes the store part of this operation `Relaxed`, and using `Release` makes the successful load `Relaxed`. The failure ordering can only be `SeqCst`, `Acquire` or `Relaxed`.
     pub fn compare_exchange_weak(&self, current: T, new: T, success: Ordering, failure: Ordering) -> Result<T, T> {
-        Self::CMP_EX_WEAK(self.inner_ptr(), current, new, success, failure)
+        Self::select_op(Self::PTR_CMP_EX_WEAK, Self::CMP_EX_WEAK)(self.inner_ptr(), current, new, success, failure)
     }
 
     #[inline]
+    ///Performs `compare_exchange`, returning self-describing outcome instead of `Result`.
+    pub fn compare_exchange_outcome(&self, current: T, new: T, success: Ordering, failure: Ordering) -> CasOutcome<T> {
+        self.compare_exchange(current, new, success, failure).into()
+    }
+
+    #[inline]
+    ///Performs `compare_exchange`, returning whether it succeeded and value stored after operation.
+    ///
+    ///On success stored value is `new`, otherwise it is the current value, which prevented exchange.
+    pub fn compare_exchange_value(&self, current: T, new: T, success: Ordering, failure: Ordering) -> (bool, T) {
+        match self.compare_exchange(current, new, success, failure) {
+            Ok(_) => (true, new),
+            Err(actual) => (false, actual),
+        }
+    }
 
+    #[inline]
     ///Fetches the value, and applies a function to it that returns an optional new value.
     ///Returns a `Result` of `Ok(previous_value)` if the function returned `Some(_)`, else `Err(previous_value)`.
     ///
@@ -202,23 +677,412 @@ impl<T: Copy> Atomic<T> {
         }
         Err(prev)
     }
+
+    #[inline]
+    ///Shortcut to `fetch_update` with `Relaxed` ordering for both store and load.
+    ///
+    ///Suitable when no other memory needs to be synchronized with this atomic (e.g. counters or initialization within single thread).
+    pub fn fetch_update_relaxed<F: FnMut(T) -> Option<T>>(&self, cb: F) -> Result<T, T> {
+        self.fetch_update(Ordering::Relaxed, Ordering::Relaxed, cb)
+    }
+
+    ///Fetches the value, and applies a fallible function to it that returns new value.
+    ///Returns `Ok(previous_value)` if new value has been stored, or the first error returned by the function, in which case nothing is stored.
+    ///
+    ///Note: This may call the function multiple times if the value has been changed from other threads in the meantime, as long as the function returns `Ok(_)`.
+    ///
+    ///Orderings are the same as in `fetch_update`.
+    pub fn try_fetch_update<E, F: FnMut(T) -> Result<T, E>>(&self, set_order: Ordering, fetch_order: Ordering, mut cb: F) -> Result<T, E> {
+        let mut prev = self.load(fetch_order);
+        loop {
+            let next = cb(prev)?;
+            match self.compare_exchange_weak(prev, next, set_order, fetch_order) {
+                Ok(prev) => return Ok(prev),
+                Err(next_prev) => prev = next_prev,
+            }
+        }
+    }
+
+    ///Performs `fetch_update`, using `seed` as presumed current value instead of initial load.
+    ///
+    ///Useful when caller has just read the value, saving redundant load.
+    ///If `seed` is stale, first attempt fails, providing actual value to retry with.
+    ///
+    ///Note that `cb` is called with `seed` first, even if it is stale.
+    pub fn fetch_update_seeded<F: FnMut(T) -> Option<T>>(&self, seed: T, set_order: Ordering, fetch_order: Ordering, mut cb: F) -> Result<T, T> {
+        let mut prev = seed;
+        while let Some(next) = cb(prev) {
+            match self.compare_exchange_weak(prev, next, set_order, fetch_order) {
+                res @ Ok(_) => return res,
+                Err(next_prev) => prev = next_prev,
+            }
+        }
+        Err(prev)
+    }
+
+    #[inline]
+    ///Performs `fetch_update`, with the first attempt outside of the retry loop.
+    ///
+    ///Behavior is identical to `fetch_update`, but code is laid out for the case when the first attempt succeeds,
+    ///which is expected for atomics with low contention.
+    pub fn fetch_update_fast<F: FnMut(T) -> Option<T>>(&self, set_order: Ordering, fetch_order: Ordering, mut cb: F) -> Result<T, T> {
+        let prev = self.load(fetch_order);
+        let next = match cb(prev) {
+            Some(next) => next,
+            None => return Err(prev),
+        };
+        match self.compare_exchange_weak(prev, next, set_order, fetch_order) {
+            res @ Ok(_) => res,
+            Err(next_prev) => self.fetch_update_seeded(next_prev, set_order, fetch_order, cb),
+        }
+    }
+
+    ///Performs `fetch_update`, spinning pseudo-random number of iterations after each failed attempt.
+    ///
+    ///Jitter desynchronizes contending threads, reducing chance of them failing again all at once.
+    ///Pseudo-random number is generated via xorshift seeded from observed value and address of the caller's stack.
+    ///
+    ///Orderings are the same as in `fetch_update`.
+    pub fn fetch_update_jitter<F: FnMut(T) -> Option<T>>(&self, set_order: Ordering, fetch_order: Ordering, mut cb: F) -> Result<T, T> {
+        //Mask for maximum number of spins per retry
+        const MAX_SPINS: u64 = 0x3f;
+
+        let mut prev = self.load(fetch_order);
+        //Each thread has own stack so it is cheap source of difference between contending threads
+        let mut rng = &prev as *const T as usize as u64;
+        while let Some(next) = cb(prev) {
+            match self.compare_exchange_weak(prev, next, set_order, fetch_order) {
+                res @ Ok(_) => return res,
+                Err(next_prev) => {
+                    prev = next_prev;
+
+                    rng ^= to_bits(&prev) | 1;
+                    rng ^= rng << 13;
+                    rng ^= rng >> 7;
+                    rng ^= rng << 17;
+                    for _ in 0..=(rng & MAX_SPINS) {
+                        core::hint::spin_loop();
+                    }
+                }
+            }
+        }
+        Err(prev)
+    }
+
+    ///Performs `fetch_update`, calling `on_retry` after each failed attempt.
+    ///
+    ///Intended for cooperative schedulers, where `on_retry` can yield to other tasks instead of busy-spinning.
+    ///
+    ///Orderings are the same as in `fetch_update`.
+    pub fn fetch_update_yield<F: FnMut(T) -> Option<T>, Y: FnMut()>(&self, set_order: Ordering, fetch_order: Ordering, mut cb: F, mut on_retry: Y) -> Result<T, T> {
+        let mut prev = self.load(fetch_order);
+        while let Some(next) = cb(prev) {
+            match self.compare_exchange_weak(prev, next, set_order, fetch_order) {
+                res @ Ok(_) => return res,
+                Err(next_prev) => {
+                    prev = next_prev;
+                    on_retry();
+                }
+            }
+        }
+        Err(prev)
+    }
+
+    ///Stores `new` if `key` of the current value is equal to `expected`.
+    ///
+    ///Allows optimistic updates keyed on part of the value (e.g. version field of packed word), while ignoring the rest of it.
+    ///Returns `Ok(previous_value)` on success, otherwise `Err(current_value)`.
+    ///
+    ///Orderings are the same as in `fetch_update`.
+    pub fn compare_exchange_by<K: PartialEq, F: Fn(T) -> K>(&self, key: F, expected: K, new: T, set_order: Ordering, fetch_order: Ordering) -> Result<T, T> {
+        self.fetch_update(set_order, fetch_order, |current| if key(current) == expected {
+            Some(new)
+        } else {
+            None
+        })
+    }
+
+    ///Performs `fetch_update`, giving up retries once `clock` reaches `deadline`.
+    ///
+    ///`clock` is user supplied tick counter (e.g. systick), so it works without `std`.
+    ///It is checked after each failed attempt, hence at least one attempt is always made.
+    ///Returns `Err(last_observed_value)` if `cb` returns `None` or deadline is reached.
+    ///
+    ///Orderings are the same as in `fetch_update`.
+    pub fn fetch_update_deadline<F: FnMut(T) -> Option<T>, C: FnMut() -> u64>(&self, set_order: Ordering, fetch_order: Ordering, mut cb: F, mut clock: C, deadline: u64) -> Result<T, T> {
+        let mut prev = self.load(fetch_order);
+        while let Some(next) = cb(prev) {
+            match self.compare_exchange_weak(prev, next, set_order, fetch_order) {
+                res @ Ok(_) => return res,
+                Err(next_prev) => {
+                    prev = next_prev;
+                    if clock() >= deadline {
+                        break;
+                    }
+                }
+            }
+        }
+        Err(prev)
+    }
+
+    ///Performs `fetch_update`, calling `on_attempt` with the observed value before each `compare_exchange` attempt.
+    ///
+    ///This allows to instrument retry loop (e.g. collect diagnostic samples).
+    ///`on_attempt` is not called if `cb` returns `None`, as no attempt is made then.
+    ///
+    ///Orderings are the same as in `fetch_update`.
+    pub fn fetch_update_with_attempt<F: FnMut(T) -> Option<T>, A: FnMut(T)>(&self, set_order: Ordering, fetch_order: Ordering, mut cb: F, mut on_attempt: A) -> Result<T, T> {
+        let mut prev = self.load(fetch_order);
+        while let Some(next) = cb(prev) {
+            on_attempt(prev);
+            match self.compare_exchange_weak(prev, next, set_order, fetch_order) {
+                res @ Ok(_) => return res,
+                Err(next_prev) => prev = next_prev,
+            }
+        }
+        Err(prev)
+    }
+
+    ///Performs `fetch_update`, storing committed value into `mirror` with `Release` ordering on success.
+    ///
+    ///Note that it is not atomic across both atomics: observers may see updated `self` while `mirror` still holds stale value.
+    ///Only the window between them is reduced, as `mirror` is updated right after successful commit.
+    ///
+    ///Orderings are the same as in `fetch_update`.
+    pub fn fetch_update_publish<F: FnMut(T) -> Option<T>>(&self, mirror: &Atomic<T>, set_order: Ordering, fetch_order: Ordering, mut cb: F) -> Result<T, T> {
+        let mut committed = None;
+        let result = self.fetch_update(set_order, fetch_order, |prev| {
+            committed = cb(prev);
+            committed
+        });
+        if let (Ok(_), Some(committed)) = (result, committed) {
+            mirror.store(committed, Ordering::Release);
+        }
+        result
+    }
+
+    ///Loads value repeatedly until two consecutive loads return the same value, returning it.
+    ///
+    ///Values are compared bitwise. This avoids observing value in the middle of burst of writes,
+    ///but note that it loops for as long as value keeps changing between loads.
+    pub fn read_stable(&self, order: Ordering) -> T {
+        let mut prev = self.load(order);
+        loop {
+            let value = self.load(order);
+            if bits_eq(&prev, &value) {
+                return value;
+            }
+            prev = value;
+            core::hint::spin_loop();
+        }
+    }
+
+    ///Stores `new` if the current value is the same as `current`, retrying for as long as `keep_going` returns `true`.
+    ///
+    ///Returns `Ok(previous_value)` once `new` is stored, or `Err(actual_value)` of the last attempt when `keep_going` returns `false`.
+    ///This allows to spin waiting for `current` value, while remaining cancellable (e.g. by shutdown flag).
+    ///
+    ///Orderings are the same as in `compare_exchange`.
+    pub fn compare_exchange_while<F: Fn() -> bool>(&self, current: T, new: T, keep_going: F, set_order: Ordering, fetch_order: Ordering) -> Result<T, T> {
+        loop {
+            match self.compare_exchange_weak(current, new, set_order, fetch_order) {
+                Ok(prev) => return Ok(prev),
+                Err(actual) => if !keep_going() {
+                    return Err(actual);
+                },
+            }
+            core::hint::spin_loop();
+        }
+    }
+
+    #[allow(clippy::result_unit_err)]
+    ///Loads value repeatedly until it satisfies `pred`, returning it, or until `clock` reaches `deadline`.
+    ///
+    ///`clock` is user supplied tick counter (e.g. systick), so it works without `std`.
+    ///Value is always checked before `clock`, hence `Err(())` is returned only if `pred` is not satisfied once `clock() >= deadline`.
+    pub fn wait_until_timeout<F: Fn(T) -> bool, C: FnMut() -> u64>(&self, pred: F, mut clock: C, deadline: u64, order: Ordering) -> Result<T, ()> {
+        loop {
+            let value = self.load(order);
+            if pred(value) {
+                return Ok(value);
+            } else if clock() >= deadline {
+                return Err(());
+            }
+            core::hint::spin_loop();
+        }
+    }
+
+    ///Performs read-modify-write operation, which stores back the current value, returning it.
+    ///
+    ///Value is never changed, but unlike plain `load` this is RMW operation, acting as barrier on this particular atomic:
+    ///it is ordered with respect to other RMW operations on it according to `order`.
+    ///This is useful when you need to synchronize with release sequence while also releasing own writes to subsequent readers.
+    ///
+    ///Failure ordering is derived from `order` as the strongest one allowed.
+    pub fn barrier_cas(&self, order: Ordering) -> T {
+        match self.fetch_update(order, failure_ordering(order), Some) {
+            Ok(value) => value,
+            Err(value) => value,
+        }
+    }
+}
+
+impl<T: Copy + PartialEq> Atomic<T> {
+    #[inline]
+    ///Takes value out, leaving `empty` sentinel in its place.
+    ///
+    ///Returns `None` if slot already contained `empty`.
+    pub fn swap_take(&self, empty: T, order: Ordering) -> Option<T> {
+        let value = self.swap(empty, order);
+        if value == empty {
+            None
+        } else {
+            Some(value)
+        }
+    }
+
+    #[inline]
+    ///Stores `new` if the current value is any of `accepted`, returning the previous value.
+    ///
+    ///Returns `Err(current)` if the current value is not accepted.
+    ///Orderings are the same as in `fetch_update`.
+    pub fn compare_exchange_any(&self, accepted: &[T], new: T, set_order: Ordering, fetch_order: Ordering) -> Result<T, T> {
+        self.fetch_update(set_order, fetch_order, |current| if accepted.contains(&current) {
+            Some(new)
+        } else {
+            None
+        })
+    }
+
+    #[inline]
+    #[allow(clippy::result_unit_err)]
+    ///Takes value out of one-shot slot, leaving `empty` sentinel in its place.
+    ///
+    ///Returns `Err(())` if slot already contained `empty`, which indicates that value has been already consumed.
+    pub fn swap_once(&self, empty: T, order: Ordering) -> Result<T, ()> {
+        self.swap_take(empty, order).ok_or(())
+    }
+
+    #[inline]
+    ///Loads value with `Relaxed` ordering, upgrading to `Acquire` only if it differs from `last`.
+    ///
+    ///Returns loaded value and whether it differs from `last`.
+    ///When value is changed, acquire fence is issued, so writes published alongside new value are visible.
+    ///
+    ///Intended for cheap polling of read-mostly values, paying for synchronization only on change.
+    pub fn load_relaxed_then_acquire_if_changed(&self, last: T) -> (T, bool) {
+        let value = self.load(Ordering::Relaxed);
+        if value == last {
+            (value, false)
+        } else {
+            atomic::fence(effective_ordering(Ordering::Acquire));
+            (value, true)
+        }
+    }
+}
+
+#[cfg(feature = "debug-checks")]
+impl<T: Copy + PartialEq> Atomic<T> {
+    ///Performs `fetch_update`, additionally reporting whether potential ABA has been observed.
+    ///
+    ///Returns result of `fetch_update` and flag which is `true` if initially observed value has been seen again after intervening different value.
+    ///
+    ///This is diagnostic only: ABA that happens entirely between two observations of the loop cannot be detected.
+    pub fn fetch_update_aba_check<F: FnMut(T) -> Option<T>>(&self, set_order: Ordering, fetch_order: Ordering, mut cb: F) -> (Result<T, T>, bool) {
+        let first = self.load(fetch_order);
+        let mut prev = first;
+        let mut is_changed = false;
+        let mut is_aba = false;
+
+        while let Some(next) = cb(prev) {
+            match self.compare_exchange_weak(prev, next, set_order, fetch_order) {
+                res @ Ok(_) => return (res, is_aba),
+                Err(next_prev) => {
+                    if next_prev != first {
+                        is_changed = true;
+                    } else if is_changed {
+                        is_aba = true;
+                    }
+                    prev = next_prev;
+                }
+            }
+        }
+
+        (Err(prev), is_aba)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T: Copy + core::panic::RefUnwindSafe> Atomic<T> {
+    ///Performs `fetch_update`, catching panic of `cb`.
+    ///
+    ///If `cb` panics, atomic is left unchanged and panic's payload is returned as error.
+    ///Note that state captured by `cb` may be left in the middle of modification.
+    pub fn fetch_update_catch<F: FnMut(T) -> Option<T>>(&self, set_order: Ordering, fetch_order: Ordering, cb: F) -> Result<Result<T, T>, std::boxed::Box<dyn core::any::Any + Send>> {
+        std::panic::catch_unwind(core::panic::AssertUnwindSafe(|| self.fetch_update(set_order, fetch_order, cb)))
+    }
+}
+
+#[cfg(feature = "async")]
+impl<T: Copy> Atomic<T> {
+    ///Fetches the value, and applies a function returning future, which resolves to optional new value.
+    ///Returns a `Result` of `Ok(previous_value)` if the future resolved to `Some(_)`, else `Err(previous_value)`.
+    ///
+    ///Note: This may call the function and await returned future multiple times if the value has been changed from other threads in the meantime, as long as the future resolves to `Some(_)`.
+    ///
+    ///Only the function is asynchronous, atomic itself is updated using `compare_exchange`.
+    ///Strong version is used to avoid awaiting on spurious failure.
+    ///
+    ///Orderings are the same as in `fetch_update`.
+    pub async fn async_fetch_update<Fut: core::future::Future<Output = Option<T>>, F: FnMut(T) -> Fut>(&self, set_order: Ordering, fetch_order: Ordering, mut cb: F) -> Result<T, T> {
+        let mut prev = self.load(fetch_order);
+        while let Some(next) = cb(prev).await {
+            match self.compare_exchange(prev, next, set_order, fetch_order) {
+                res @ Ok(_) => return res,
+                Err(next_prev) => prev = next_prev,
+            }
+        }
+        Err(prev)
+    }
 }
 
 macro_rules! impl_common_spec {
     ($($ty:ident($atomic:ident)),*) => {$(
         impl Atomic<$ty> {
+            ///Returns a mutable reference to the underlying atomic of the backend.
+            ///
+            ///This is safe because the mutable reference guarantees that no other threads are concurrently accessing the atomic data.
+            ///Note that with `critical-section-polyfill` it is polyfill's atomic, rather than `core`'s.
+            #[inline]
+            pub fn get_mut_atomic(&mut self) -> &mut atomic::$atomic {
+                unsafe {
+                    &mut *(self.inner_ptr() as *mut atomic::$atomic)
+                }
+            }
+
+            ///Consumes the atomic, returning atomic of the backend initialized with the contained value.
+            ///
+            ///Note that with `critical-section-polyfill` it is polyfill's atomic, rather than `core`'s.
+            #[inline]
+            pub fn into_std(self) -> atomic::$atomic {
+                atomic::$atomic::new(self.into_inner())
+            }
+
             /// Bitwise and with the current value, returning the previous value.
             #[inline]
             pub fn fetch_and(&self, val: $ty, order: Ordering) -> $ty {
                 unsafe {
-                    (*(self.inner_ptr() as *const atomic::$atomic)).fetch_and(val, order)
+                    (*(self.inner_ptr() as *const atomic::$atomic)).fetch_and(val, effective_ordering(order))
                 }
             }
-            /// Bitwise nand with the current value.
+            /// Bitwise nand with the current value, returning the previous value.
+            ///
+            /// Stores `!(current & val)`, same as `fetch_nand` of `core` atomics.
+            /// Hence nand with all bits set inverts the current value, while nand with zero sets all bits.
             #[inline]
             pub fn fetch_nand(&self, val: $ty, order: Ordering) -> $ty {
                 unsafe {
-                    (*(self.inner_ptr() as *const atomic::$atomic)).fetch_nand(val, order)
+                    (*(self.inner_ptr() as *const atomic::$atomic)).fetch_nand(val, effective_ordering(order))
                 }
             }
 
@@ -226,7 +1090,7 @@ macro_rules! impl_common_spec {
             #[inline]
             pub fn fetch_or(&self, val: $ty, order: Ordering) -> $ty {
                 unsafe {
-                    (*(self.inner_ptr() as *const atomic::$atomic)).fetch_or(val, order)
+                    (*(self.inner_ptr() as *const atomic::$atomic)).fetch_or(val, effective_ordering(order))
                 }
             }
 
@@ -234,7 +1098,7 @@ macro_rules! impl_common_spec {
             #[inline]
             pub fn fetch_xor(&self, val: $ty, order: Ordering) -> $ty {
                 unsafe {
-                    (*(self.inner_ptr() as *const atomic::$atomic)).fetch_xor(val, order)
+                    (*(self.inner_ptr() as *const atomic::$atomic)).fetch_xor(val, effective_ordering(order))
                 }
             }
         }
@@ -246,25 +1110,33 @@ macro_rules! impl_math_spec {
         impl Atomic<$ty> {
             #[inline]
             /// Minimum with the current value.
+            ///
+            /// Stores minimum of the current value and `val`, returning the previous value.
+            /// Comparison is performed according to signedness of the integer, so it never wraps around.
             pub fn fetch_min(&self, val: $ty, order: Ordering) -> $ty {
                 unsafe {
-                    (*(self.inner_ptr() as *const atomic::$atomic)).fetch_min(val, order)
+                    (*(self.inner_ptr() as *const atomic::$atomic)).fetch_min(val, effective_ordering(order))
                 }
             }
 
             #[inline]
             /// Maximum with the current value.
+            ///
+            /// Stores maximum of the current value and `val`, returning the previous value.
+            /// Comparison is performed according to signedness of the integer, so it never wraps around.
             pub fn fetch_max(&self, val: $ty, order: Ordering) -> $ty {
                 unsafe {
-                    (*(self.inner_ptr() as *const atomic::$atomic)).fetch_max(val, order)
+                    (*(self.inner_ptr() as *const atomic::$atomic)).fetch_max(val, effective_ordering(order))
                 }
             }
 
             #[inline]
             /// Adds to the current value, returning the previous value.
+            ///
+            /// This operation wraps around on overflow.
             pub fn fetch_add(&self, val: $ty, order: Ordering) -> $ty {
                 unsafe {
-                    (*(self.inner_ptr() as *const atomic::$atomic)).fetch_add(val, order)
+                    (*(self.inner_ptr() as *const atomic::$atomic)).fetch_add(val, effective_ordering(order))
                 }
             }
 
@@ -272,9 +1144,147 @@ macro_rules! impl_math_spec {
             #[inline]
             pub fn fetch_sub(&self, val: $ty, order: Ordering) -> $ty {
                 unsafe {
-                    (*(self.inner_ptr() as *const atomic::$atomic)).fetch_sub(val, order)
+                    (*(self.inner_ptr() as *const atomic::$atomic)).fetch_sub(val, effective_ordering(order))
+                }
+            }
+
+            #[inline]
+            /// Increments the current value by one, returning the previous value.
+            ///
+            /// This is the same as `fetch_add(1)`, hence it never involves CAS loop and wraps around on overflow.
+            pub fn increment(&self, order: Ordering) -> $ty {
+                self.fetch_add(1, order)
+            }
+
+            #[inline]
+            /// Decrements the current value by one, returning the previous value.
+            ///
+            /// This is the same as `fetch_sub(1)`, hence it never involves CAS loop and wraps around on overflow.
+            pub fn decrement(&self, order: Ordering) -> $ty {
+                self.fetch_sub(1, order)
+            }
+
+            #[inline]
+            /// Adds to the current value, returning the previous value.
+            ///
+            /// This is the same as `fetch_add`, named to emphasize that it never panics and instead wraps around on overflow.
+            pub fn fetch_add_wrapping(&self, val: $ty, order: Ordering) -> $ty {
+                self.fetch_add(val, order)
+            }
+
+            #[inline]
+            /// Reverses byte order of the current value, returning the previous value.
+            ///
+            /// Failure ordering is derived from `order` as the strongest one allowed.
+            pub fn fetch_swap_bytes(&self, order: Ordering) -> $ty {
+                match self.fetch_update(order, failure_ordering(order), |value| Some(value.swap_bytes())) {
+                    Ok(prev) => prev,
+                    Err(prev) => prev,
+                }
+            }
+
+            #[inline]
+            /// Sets bits of `mask`, returning whether all bits of `required` are set afterwards.
+            ///
+            /// Useful to detect that the last of workers has set its bit, e.g. to release barrier.
+            /// Note that it returns `true` for every call made once `required` bits are set, not only for the one that completed it.
+            pub fn fetch_or_check(&self, mask: $ty, required: $ty, order: Ordering) -> bool {
+                let value = self.fetch_or(mask, order) | mask;
+                value & required == required
+            }
+
+            #[inline]
+            /// Sets the lowest clear bit, returning its index.
+            ///
+            /// Returns `None` if all bits are already set.
+            /// This is the core of bitmap allocator, where each bit marks occupied slot.
+            ///
+            /// Failure ordering is derived from `order` as the strongest one allowed.
+            pub fn claim_first_clear(&self, order: Ordering) -> Option<u32> {
+                let prev = self.fetch_update(order, failure_ordering(order), |value| if value == !0 {
+                    None
+                } else {
+                    Some(value | (value.wrapping_add(1) & !value))
+                }).ok()?;
+                Some((!prev).trailing_zeros())
+            }
+
+            #[inline]
+            /// Replaces bits of `mask` with `new_masked`, if bits of `mask` are equal to `expected_masked`.
+            ///
+            /// Bits outside of `mask` are ignored by comparison and preserved, so they can be modified concurrently.
+            /// This allows to update independent fields within the same word.
+            ///
+            /// Returns `Ok(previous_value)` on success, otherwise `Err(current_value)`.
+            /// Orderings are the same as in `fetch_update`.
+            pub fn compare_exchange_masked(&self, mask: $ty, expected_masked: $ty, new_masked: $ty, set_order: Ordering, fetch_order: Ordering) -> Result<$ty, $ty> {
+                self.fetch_update(set_order, fetch_order, |current| if current & mask == expected_masked & mask {
+                    Some((current & !mask) | (new_masked & mask))
+                } else {
+                    None
+                })
+            }
+
+            #[inline]
+            /// Publishes `val` if atomic is still zero, returning whether this call did the publish.
+            ///
+            /// Zero is sentinel of not yet published value, hence `val` must not be zero.
+            /// Once value is published, it is cheap no-op, consisting of single relaxed load.
+            /// Publishing is done via `compare_exchange` with `order`, which should be `Release` (or stronger) to publish writes made before it.
+            pub fn publish_once(&self, val: $ty, order: Ordering) -> bool {
+                debug_assert_ne!(val, 0, "Atomic: cannot publish zero sentinel");
+                self.load(Ordering::Relaxed) == 0 && self.compare_exchange(0, val, order, Ordering::Relaxed).is_ok()
+            }
+
+            #[inline]
+            /// Resets counter to zero, returning amount accumulated since the previous reset.
+            pub fn drain(&self, order: Ordering) -> $ty {
+                self.swap(0, order)
+            }
+
+            #[inline]
+            /// Maximum with the current value, returning the new maximum.
+            ///
+            /// Unlike `fetch_max` result is value stored as result of this operation.
+            pub fn update_max(&self, candidate: $ty, order: Ordering) -> $ty {
+                core::cmp::max(self.fetch_max(candidate, order), candidate)
+            }
+
+            #[cfg(any(feature = "critical-section-polyfill", target_has_atomic = "8"))]
+            #[inline]
+            ///Reinterprets storage as array of byte atomics, in native byte order.
+            ///
+            ///Each byte lane is atomic on its own, but there is no atomicity of the whole value:
+            ///reader may observe mix of old and new bytes, if lanes are written separately.
+            ///
+            ///Byte lanes must not be accessed concurrently with operations on the whole value, as mixed-size racing accesses are not allowed.
+            pub fn as_byte_atomics(&self) -> &[atomic::AtomicU8; mem::size_of::<$ty>()] {
+                unsafe {
+                    &*(self.inner_ptr() as *const [atomic::AtomicU8; mem::size_of::<$ty>()])
                 }
             }
+
+            ///Creates atomic reference into `buf` at specified `offset`.
+            ///
+            ///Returns `None` if `offset` is not aligned for the atomic or value doesn't fit into `buf`.
+            ///
+            ///Useful to place atomics into externally allocated memory (e.g. shared memory region).
+            ///Any bit pattern is valid integer, hence it is safe to reinterpret bytes as atomic.
+            ///
+            ///With `debug-checks` and `std` features, debug builds track memory of returned reference while it is alive
+            ///and panic if atomics of different width overlap.
+            pub fn from_bytes_mut(buf: &mut [u8], offset: usize) -> Option<AtomicBytesRef<'_, $ty>> {
+                let end = offset.checked_add(mem::size_of::<$ty>())?;
+                let bytes = buf.get_mut(offset..end)?;
+                let ptr = bytes.as_mut_ptr();
+                if ptr as usize % mem::align_of::<atomic::$atomic>() != 0 {
+                    return None;
+                }
+
+                Some(AtomicBytesRef::new(unsafe {
+                    &*(ptr as *const Self)
+                }))
+            }
         }
     )*};
 }
@@ -319,6 +1329,73 @@ impl_math_spec!(i64(AtomicI64), u64(AtomicU64));
 )]
 impl_math_spec!(isize(AtomicIsize), usize(AtomicUsize));
 
+#[cfg(all(feature = "atomic-128", target_has_atomic = "128"))]
+mod wide {
+    //128bit atomics are never provided by polyfill, so `core` is used regardless of backend
+    use core::sync::atomic;
+
+    use super::*;
+
+    impl_common_spec!(i128(AtomicI128), u128(AtomicU128));
+    impl_math_spec!(i128(AtomicI128), u128(AtomicU128));
+}
+
+#[cfg(any(feature = "critical-section-polyfill", target_has_atomic = "ptr"))]
+impl Atomic<usize> {
+    ///Adds `delta` to the bits not covered by `mask`, leaving bits within `mask` untouched, and returns the previous value.
+    ///
+    ///This is intended for tagged words, where `mask` covers tag bits while the rest is counter.
+    ///Note that `delta` is added to the word as it is, so it must be shifted by the caller (e.g. `1 << 3` to increment counter stored above 3 tag bits).
+    ///Overflow of counter bits wraps around, without affecting tag.
+    pub fn fetch_add_masked(&self, delta: usize, mask: usize, order: Ordering) -> usize {
+        let result = self.fetch_update(order, failure_ordering(order), |prev| {
+            let counter = (prev & !mask).wrapping_add(delta) & !mask;
+            Some((prev & mask) | counter)
+        });
+        match result {
+            Ok(prev) => prev,
+            Err(prev) => prev,
+        }
+    }
+
+    ///Advances index by one modulo `modulus`, returning the previous index.
+    ///
+    ///Intended for ring buffer cursors, where index wraps around to zero after reaching capacity.
+    ///
+    ///Failure ordering is derived from `order` as the strongest one allowed.
+    ///
+    ///# Panics
+    ///
+    ///If `modulus` is zero.
+    pub fn fetch_wrapping_inc(&self, modulus: usize, order: Ordering) -> usize {
+        assert_ne!(modulus, 0, "Atomic<usize>: modulus must not be zero");
+        let result = self.fetch_update(order, failure_ordering(order), |idx| Some((idx % modulus + 1) % modulus));
+        match result {
+            Ok(prev) => prev,
+            Err(prev) => prev,
+        }
+    }
+}
+
+#[cfg(any(feature = "critical-section-polyfill", target_has_atomic = "8"))]
+impl Atomic<u8> {
+    ///Applies `event` to the state machine, according to transition `table`.
+    ///
+    ///Each row of `table` is `(from_state, event, to_state)`, first row matching current state and `event` is applied.
+    ///Returns new state if transition is performed, otherwise `Err(current_state)`.
+    ///
+    ///Failure ordering is derived from `order` as the strongest one allowed.
+    pub fn transition_table(&self, event: u8, table: &[(u8, u8, u8)], order: Ordering) -> Result<u8, u8> {
+        let mut new_state = 0;
+        let result = self.fetch_update(order, failure_ordering(order), |state| {
+            let row = table.iter().find(|(from, row_event, _)| *from == state && *row_event == event)?;
+            new_state = row.2;
+            Some(new_state)
+        });
+        result.map(|_| new_state)
+    }
+}
+
 impl<T: Copy + fmt::Debug> fmt::Debug for Atomic<T> {
     #[inline(always)]
     fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -326,6 +1403,15 @@ impl<T: Copy + fmt::Debug> fmt::Debug for Atomic<T> {
     }
 }
 
+#[cfg(feature = "defmt")]
+impl<T: Copy + defmt::Format> defmt::Format for Atomic<T> {
+    #[inline(always)]
+    ///Formats value loaded with `Relaxed` ordering.
+    fn format(&self, fmt: defmt::Formatter<'_>) {
+        self.load(Ordering::Relaxed).format(fmt)
+    }
+}
+
 impl<T> From<T> for Atomic<T> {
     #[inline(always)]
     fn from(value: T) -> Self {
@@ -0,0 +1,84 @@
+use crate::{Atomic, Ordering};
+
+///Meter of CAS success ratio, intended to tune algorithms according to contention.
+///
+///Counters are updated with `Relaxed` ordering, as they are statistics only.
+pub struct ContentionMeter {
+    attempts: Atomic<u64>,
+    successes: Atomic<u64>,
+}
+
+impl ContentionMeter {
+    #[inline]
+    ///Creates a new instance
+    pub const fn new() -> Self {
+        Self {
+            attempts: Atomic::new(0),
+            successes: Atomic::new(0),
+        }
+    }
+
+    #[inline]
+    ///Records CAS attempt.
+    pub fn record_attempt(&self) {
+        self.attempts.fetch_add(1, Ordering::Relaxed);
+    }
+
+    #[inline]
+    ///Records successful CAS.
+    pub fn record_success(&self) {
+        self.successes.fetch_add(1, Ordering::Relaxed);
+    }
+
+    #[inline]
+    ///Returns number of recorded attempts and successes.
+    pub fn counts(&self) -> (u64, u64) {
+        (self.attempts.load(Ordering::Relaxed), self.successes.load(Ordering::Relaxed))
+    }
+
+    ///Returns ratio of successful attempts, within `0.0..=1.0`.
+    ///
+    ///If there are no attempts recorded, returns `1.0` as there is no contention.
+    pub fn ratio(&self) -> f32 {
+        let (attempts, successes) = self.counts();
+        if attempts == 0 {
+            1.0
+        } else {
+            successes as f32 / attempts as f32
+        }
+    }
+
+    #[inline]
+    ///Resets counters, starting new measurement window.
+    pub fn reset(&self) {
+        self.attempts.store(0, Ordering::Relaxed);
+        self.successes.store(0, Ordering::Relaxed);
+    }
+}
+
+impl Default for ContentionMeter {
+    #[inline(always)]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Copy> Atomic<T> {
+    ///Performs `fetch_update`, recording every CAS attempt and its success into `meter`.
+    ///
+    ///Orderings are the same as in `fetch_update`.
+    pub fn fetch_update_metered<F: FnMut(T) -> Option<T>>(&self, meter: &ContentionMeter, set_order: Ordering, fetch_order: Ordering, mut cb: F) -> Result<T, T> {
+        let mut prev = self.load(fetch_order);
+        while let Some(next) = cb(prev) {
+            meter.record_attempt();
+            match self.compare_exchange_weak(prev, next, set_order, fetch_order) {
+                res @ Ok(_) => {
+                    meter.record_success();
+                    return res;
+                },
+                Err(next_prev) => prev = next_prev,
+            }
+        }
+        Err(prev)
+    }
+}
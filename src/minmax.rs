@@ -0,0 +1,80 @@
+use core::fmt;
+
+use crate::{Atomic, Ordering};
+
+///Tracker of minimum and maximum among recorded samples.
+///
+///Minimum and maximum are stored in separate atomics, hence they are not updated atomically as a pair:
+///reader may observe sample already accounted in one of them, but not yet in another.
+pub struct MinMax<T> {
+    min: Atomic<T>,
+    max: Atomic<T>,
+}
+
+macro_rules! impl_min_max {
+    ($($ty:ident),*) => {$(
+        impl MinMax<$ty> {
+            #[inline]
+            ///Creates a new instance, without any samples recorded.
+            ///
+            ///Until first sample is recorded, `min` returns `MAX` while `max` returns `MIN`.
+            pub const fn new() -> Self {
+                Self {
+                    min: Atomic::new($ty::MAX),
+                    max: Atomic::new($ty::MIN),
+                }
+            }
+
+            #[inline]
+            ///Records `sample`, updating both minimum and maximum.
+            pub fn record(&self, sample: $ty, order: Ordering) {
+                self.min.fetch_min(sample, order);
+                self.max.fetch_max(sample, order);
+            }
+
+            #[inline]
+            ///Returns minimum among recorded samples.
+            pub fn min(&self, order: Ordering) -> $ty {
+                self.min.load(order)
+            }
+
+            #[inline]
+            ///Returns maximum among recorded samples.
+            pub fn max(&self, order: Ordering) -> $ty {
+                self.max.load(order)
+            }
+        }
+
+        impl Default for MinMax<$ty> {
+            #[inline(always)]
+            fn default() -> Self {
+                Self::new()
+            }
+        }
+
+        impl fmt::Debug for MinMax<$ty> {
+            fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+                fmt.debug_struct("MinMax").field("min", &self.min(Ordering::Relaxed)).field("max", &self.max(Ordering::Relaxed)).finish()
+            }
+        }
+    )*};
+}
+
+#[cfg(any(feature = "critical-section-polyfill", target_has_atomic = "8"))]
+impl_min_max!(i8, u8);
+#[cfg(any(feature = "critical-section-polyfill", target_has_atomic = "16"))]
+impl_min_max!(i16, u16);
+#[cfg(any(feature = "critical-section-polyfill", target_has_atomic = "32"))]
+impl_min_max!(i32, u32);
+#[cfg(any(feature = "critical-section-polyfill", target_has_atomic = "64"))]
+impl_min_max!(i64, u64);
+
+#[cfg(
+    any(
+        feature = "critical-section-polyfill",
+        all(target_has_atomic = "64", target_pointer_width = "64"),
+        all(target_has_atomic = "32", target_pointer_width = "32"),
+        all(target_has_atomic = "16", target_pointer_width = "16"),
+    )
+)]
+impl_min_max!(isize, usize);
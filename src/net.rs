@@ -0,0 +1,79 @@
+use core::fmt;
+use core::net::Ipv4Addr;
+
+use crate::{Atomic, Ordering};
+
+#[repr(transparent)]
+///Atomic IPv4 address.
+///
+///`Ipv4Addr` has alignment of `u8`, hence it cannot be used with `Atomic` directly.
+///Instead address is stored as `u32` (same as `u32::from(Ipv4Addr)`), converting on every access.
+pub struct AtomicIpv4 {
+    inner: Atomic<u32>,
+}
+
+impl AtomicIpv4 {
+    #[inline]
+    ///Creates a new instance
+    pub const fn new(addr: Ipv4Addr) -> Self {
+        Self {
+            inner: Atomic::new(u32::from_be_bytes(addr.octets())),
+        }
+    }
+
+    #[inline]
+    ///Loads address.
+    pub fn load_addr(&self, order: Ordering) -> Ipv4Addr {
+        Ipv4Addr::from(self.inner.load(order))
+    }
+
+    #[inline]
+    ///Stores address.
+    pub fn store_addr(&self, addr: Ipv4Addr, order: Ordering) {
+        self.inner.store(u32::from(addr), order)
+    }
+
+    #[inline]
+    ///Stores address, returning the previous address.
+    pub fn swap_addr(&self, addr: Ipv4Addr, order: Ordering) -> Ipv4Addr {
+        Ipv4Addr::from(self.inner.swap(u32::from(addr), order))
+    }
+
+    #[inline]
+    ///Stores `new` address if the current address is the same as `current`.
+    ///
+    ///Same as `Atomic::compare_exchange`.
+    pub fn compare_exchange_addr(&self, current: Ipv4Addr, new: Ipv4Addr, success: Ordering, failure: Ordering) -> Result<Ipv4Addr, Ipv4Addr> {
+        match self.inner.compare_exchange(u32::from(current), u32::from(new), success, failure) {
+            Ok(addr) => Ok(Ipv4Addr::from(addr)),
+            Err(addr) => Err(Ipv4Addr::from(addr)),
+        }
+    }
+
+    #[inline]
+    ///Consumes the atomic and returns the contained address.
+    pub fn into_inner(self) -> Ipv4Addr {
+        Ipv4Addr::from(self.inner.into_inner())
+    }
+}
+
+impl Default for AtomicIpv4 {
+    #[inline(always)]
+    fn default() -> Self {
+        Self::new(Ipv4Addr::UNSPECIFIED)
+    }
+}
+
+impl From<Ipv4Addr> for AtomicIpv4 {
+    #[inline(always)]
+    fn from(addr: Ipv4Addr) -> Self {
+        Self::new(addr)
+    }
+}
+
+impl fmt::Debug for AtomicIpv4 {
+    #[inline(always)]
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&self.load_addr(Ordering::Relaxed), fmt)
+    }
+}
@@ -0,0 +1,141 @@
+use core::{fmt, task};
+use core::cell::UnsafeCell;
+use core::future::Future;
+use core::pin::Pin;
+
+use crate::{Atomic, Ordering};
+
+//Waker slot is not accessed
+const WAITING: u8 = 0;
+//Waker slot is being updated by `register`
+const REGISTERING: u8 = 0b01;
+//Waker slot is being taken by `wake`
+const WAKING: u8 = 0b10;
+
+///Flag, which can be awaited until it is set.
+///
+///Only one task can wait on the flag at a time: registering new waker replaces the previous one.
+///
+///Waker slot is guarded by `Atomic<u8>` state, same as `AtomicWaker` of `futures` crate, so it doesn't need allocation or `std`.
+pub struct AtomicFlag {
+    flag: Atomic<bool>,
+    state: Atomic<u8>,
+    waker: UnsafeCell<Option<task::Waker>>,
+}
+
+unsafe impl Sync for AtomicFlag {}
+
+impl AtomicFlag {
+    #[inline]
+    ///Creates a new instance
+    pub const fn new(is_set: bool) -> Self {
+        Self {
+            flag: Atomic::new(is_set),
+            state: Atomic::new(WAITING),
+            waker: UnsafeCell::new(None),
+        }
+    }
+
+    #[inline]
+    ///Returns whether flag is set, using `Acquire` ordering.
+    pub fn is_set(&self) -> bool {
+        self.flag.load(Ordering::Acquire)
+    }
+
+    #[inline]
+    ///Sets flag with `Release` ordering, waking waiting task, if any.
+    pub fn set(&self) {
+        self.flag.store(true, Ordering::Release);
+        self.wake();
+    }
+
+    #[inline]
+    ///Clears flag, returning whether it was set.
+    pub fn clear(&self) -> bool {
+        self.flag.swap(false, Ordering::AcqRel)
+    }
+
+    #[inline]
+    ///Returns future, which completes once flag is set.
+    pub fn wait(&self) -> Wait<'_> {
+        Wait {
+            flag: self,
+        }
+    }
+
+    fn register(&self, waker: &task::Waker) {
+        match self.state.compare_exchange(WAITING, REGISTERING, Ordering::Acquire, Ordering::Acquire) {
+            Ok(_) => {
+                unsafe {
+                    match &mut *self.waker.get() {
+                        Some(old) if old.will_wake(waker) => (),
+                        slot => *slot = Some(waker.clone()),
+                    }
+                }
+
+                //If `wake` has been called concurrently, it is up to us to wake stored waker
+                if self.state.compare_exchange(REGISTERING, WAITING, Ordering::AcqRel, Ordering::Acquire).is_err() {
+                    let waker = unsafe {
+                        (*self.waker.get()).take()
+                    };
+                    self.state.swap(WAITING, Ordering::AcqRel);
+                    if let Some(waker) = waker {
+                        waker.wake();
+                    }
+                }
+            },
+            //Concurrent `wake` is taking waker or concurrent `register`, so poll again
+            Err(_) => waker.wake_by_ref(),
+        }
+    }
+
+    fn wake(&self) {
+        if self.state.fetch_or(WAKING, Ordering::AcqRel) == WAITING {
+            let waker = unsafe {
+                (*self.waker.get()).take()
+            };
+            self.state.fetch_and(!WAKING, Ordering::Release);
+            if let Some(waker) = waker {
+                waker.wake();
+            }
+        }
+    }
+}
+
+impl Default for AtomicFlag {
+    #[inline(always)]
+    fn default() -> Self {
+        Self::new(false)
+    }
+}
+
+impl fmt::Debug for AtomicFlag {
+    #[inline(always)]
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt.debug_struct("AtomicFlag").field("is_set", &self.is_set()).finish()
+    }
+}
+
+///Future returned by `AtomicFlag::wait`
+pub struct Wait<'a> {
+    flag: &'a AtomicFlag,
+}
+
+impl Future for Wait<'_> {
+    type Output = ();
+
+    fn poll(self: Pin<&mut Self>, ctx: &mut task::Context<'_>) -> task::Poll<Self::Output> {
+        if self.flag.is_set() {
+            return task::Poll::Ready(());
+        }
+
+        self.flag.register(ctx.waker());
+
+        //Flag could be set before waker is registered
+        if self.flag.is_set() {
+            task::Poll::Ready(())
+        } else {
+            task::Poll::Pending
+        }
+    }
+}
@@ -0,0 +1,57 @@
+use crate::{Atomic, Ordering};
+
+macro_rules! impl_store_nt {
+    ($($ty:ident => $stream:ident($raw:ident) if $arch:meta),*) => {$(
+        impl Atomic<$ty> {
+            #[inline]
+            ///Stores value using non-temporal hint, avoiding pollution of cache.
+            ///
+            ///Intended for initialization of large regions, prior to publishing them.
+            ///
+            ///Non-temporal stores are weakly ordered, hence on x86 each store is followed by `sfence`,
+            ///which makes it ordered as regular store with `order`.
+            ///
+            ///On targets without streaming stores this is the same as `store`.
+            pub fn store_nt(&self, val: $ty, order: Ordering) {
+                #[cfg($arch)]
+                {
+                    #[cfg(target_arch = "x86")]
+                    use core::arch::x86::{$stream, _mm_sfence};
+                    #[cfg(target_arch = "x86_64")]
+                    use core::arch::x86_64::{$stream, _mm_sfence};
+
+                    let order = $crate::effective_ordering(order);
+                    unsafe {
+                        //Previous stores must not be reordered past streaming store
+                        if order != Ordering::Relaxed {
+                            _mm_sfence();
+                        }
+                        $stream(self.inner_ptr() as *mut $raw, val as $raw);
+                        //Streaming store must be fenced before memory is accessed again
+                        _mm_sfence();
+                    }
+                    if order == Ordering::SeqCst {
+                        $crate::atomic::fence(Ordering::SeqCst);
+                    }
+                }
+
+                #[cfg(not($arch))]
+                {
+                    self.store(val, order)
+                }
+            }
+        }
+    )*};
+}
+
+#[cfg(any(feature = "critical-section-polyfill", target_has_atomic = "32"))]
+impl_store_nt!(
+    u32 => _mm_stream_si32(i32) if any(target_arch = "x86_64", all(target_arch = "x86", target_feature = "sse2")),
+    i32 => _mm_stream_si32(i32) if any(target_arch = "x86_64", all(target_arch = "x86", target_feature = "sse2"))
+);
+
+#[cfg(any(feature = "critical-section-polyfill", target_has_atomic = "64"))]
+impl_store_nt!(
+    u64 => _mm_stream_si64(i64) if target_arch = "x86_64",
+    i64 => _mm_stream_si64(i64) if target_arch = "x86_64"
+);
@@ -9,59 +9,84 @@ unsafe fn map_result<FROM, TO>(val: Result<FROM, FROM>) -> Result<TO, TO> {
 }
 
 macro_rules! impl_atomic_base {
+    (core: $($ty:ident($atomic:ident)),*) => {$(
+        pub mod $ty {
+            use ::core::sync::atomic::$atomic;
+
+            impl_atomic_base!(@ops $ty($atomic));
+        }
+    )*};
     ($($ty:ident($atomic:ident)),*) => {$(
         pub mod $ty {
             #[cfg(feature = "critical-section-polyfill")]
             use ::atomic_polyfill::$atomic;
             #[cfg(not(feature = "critical-section-polyfill"))]
             use ::core::sync::atomic::$atomic;
-            //transmute() doesn't work with generics, until it is fixed, use transmute_copy
-            use ::core::mem::transmute_copy;
 
-            #[allow(unused)]
-            pub fn atomic_load<T>(dst: *mut T, order: $crate::Ordering) -> T {
-                unsafe {
-                    transmute_copy(&(*(dst as *const $atomic)).load(order))
-                }
+            impl_atomic_base!(@ops $ty($atomic));
+        }
+    )*};
+    (@ops $ty:ident($atomic:ident)) => {
+        //transmute() doesn't work with generics, until it is fixed, use transmute_copy
+        use ::core::mem::transmute_copy;
+
+        #[allow(unused)]
+        pub fn atomic_load<T>(dst: *mut T, order: $crate::Ordering) -> T {
+            unsafe {
+                transmute_copy(&(*(dst as *const $atomic)).load($crate::effective_ordering(order)))
             }
+        }
 
-            #[allow(unused)]
-            pub fn atomic_store<T>(dst: *mut T, val: T, order: $crate::Ordering) {
-                unsafe {
-                    (*(dst as *const $atomic)).store(transmute_copy(&val), order)
-                }
+        #[allow(unused)]
+        pub fn atomic_store<T>(dst: *mut T, val: T, order: $crate::Ordering) {
+            unsafe {
+                (*(dst as *const $atomic)).store(transmute_copy(&val), $crate::effective_ordering(order))
             }
+        }
 
-            #[allow(unused)]
-            pub fn atomic_swap<T>(dst: *mut T, val: T, order: $crate::Ordering) -> T {
-                unsafe {
-                    transmute_copy(&(*(dst as *const $atomic)).swap(transmute_copy(&val), order))
-                }
+        #[allow(unused)]
+        pub fn atomic_swap<T>(dst: *mut T, val: T, order: $crate::Ordering) -> T {
+            unsafe {
+                transmute_copy(&(*(dst as *const $atomic)).swap(transmute_copy(&val), $crate::effective_ordering(order)))
             }
+        }
 
-            #[allow(unused)]
-            pub fn atomic_compare_exchange<T>(dst: *mut T, current: T, new: T, ok: $crate::Ordering, err: $crate::Ordering) -> Result<T, T> {
-                unsafe {
-                    $crate::ops::map_result::<$ty, T>(
-                        transmute_copy(&(*(dst as *const $atomic)).compare_exchange(
-                            transmute_copy(&current), transmute_copy(&new), ok, err)
-                        )
+        #[allow(unused)]
+        pub fn atomic_compare_exchange<T>(dst: *mut T, current: T, new: T, ok: $crate::Ordering, err: $crate::Ordering) -> Result<T, T> {
+            unsafe {
+                $crate::ops::map_result::<$ty, T>(
+                    transmute_copy(&(*(dst as *const $atomic)).compare_exchange(
+                        transmute_copy(&current), transmute_copy(&new), $crate::effective_ordering(ok), $crate::effective_ordering(err))
                     )
-                }
+                )
             }
+        }
 
-            #[allow(unused)]
-            pub fn atomic_compare_exchange_weak<T>(dst: *mut T, current: T, new: T, ok: $crate::Ordering, err: $crate::Ordering) -> Result<T, T> {
-                unsafe {
-                    $crate::ops::map_result::<$ty, T>(
-                        transmute_copy(&(*(dst as *const $atomic)).compare_exchange_weak(
-                            transmute_copy(&current), transmute_copy(&new), ok, err)
-                        )
+        #[allow(unused)]
+        pub fn atomic_compare_exchange_weak<T>(dst: *mut T, current: T, new: T, ok: $crate::Ordering, err: $crate::Ordering) -> Result<T, T> {
+            unsafe {
+                $crate::ops::map_result::<$ty, T>(
+                    transmute_copy(&(*(dst as *const $atomic)).compare_exchange_weak(
+                        transmute_copy(&current), transmute_copy(&new), $crate::effective_ordering(ok), $crate::effective_ordering(err))
                     )
-                }
+                )
             }
         }
-    )*};
+    };
+}
+
+//Pointer sized values, other than primitive numbers, go through `AtomicPtr` to preserve provenance of pointers.
+#[cfg(any(feature = "critical-section-polyfill", target_has_atomic = "ptr"))]
+pub mod ptr {
+    #[cfg(feature = "critical-section-polyfill")]
+    use ::atomic_polyfill::AtomicPtr;
+    #[cfg(not(feature = "critical-section-polyfill"))]
+    use ::core::sync::atomic::AtomicPtr;
+
+    type BytePtr = *mut u8;
+    type AtomicBytePtr = AtomicPtr<u8>;
+
+    impl_atomic_base!(@ops BytePtr(AtomicBytePtr));
 }
 
 #[cfg(any(feature = "critical-section-polyfill", target_has_atomic = "8"))]
@@ -73,3 +98,5 @@ impl_atomic_base!(u16(AtomicU16), i16(AtomicI16));
 impl_atomic_base!(u32(AtomicU32), i32(AtomicI32));
 #[cfg(any(feature = "critical-section-polyfill", target_has_atomic = "64"))]
 impl_atomic_base!(u64(AtomicU64), i64(AtomicI64));
+#[cfg(all(feature = "atomic-128", target_has_atomic = "128"))]
+impl_atomic_base!(core: u128(AtomicU128), i128(AtomicI128));
@@ -0,0 +1,45 @@
+use core::num;
+
+use crate::{failure_ordering, Atomic, Ordering};
+
+macro_rules! impl_option_spec {
+    ($($ty:ident),*) => {$(
+        impl Atomic<Option<num::$ty>> {
+            #[inline]
+            ///Takes value out, leaving `None` in its place.
+            pub fn take(&self, order: Ordering) -> Option<num::$ty> {
+                self.swap(None, order)
+            }
+
+            #[inline]
+            ///Stores `Some(val)`, returning the previous value.
+            pub fn replace(&self, val: num::$ty, order: Ordering) -> Option<num::$ty> {
+                self.swap(Some(val), order)
+            }
+
+            #[inline]
+            ///Stores `val` if the current value is `None`, returning the value stored after operation.
+            ///
+            ///Failure ordering is derived from `order` as the strongest one allowed.
+            pub fn get_or_insert(&self, val: num::$ty, order: Ordering) -> num::$ty {
+                match self.compare_exchange(None, Some(val), order, failure_ordering(order)) {
+                    Ok(_) => val,
+                    Err(Some(current)) => current,
+                    //Failure means the current value is not None
+                    Err(None) => unreachable!(),
+                }
+            }
+        }
+    )*};
+}
+
+#[cfg(any(feature = "critical-section-polyfill", target_has_atomic = "8"))]
+impl_option_spec!(NonZeroI8, NonZeroU8);
+#[cfg(any(feature = "critical-section-polyfill", target_has_atomic = "16"))]
+impl_option_spec!(NonZeroI16, NonZeroU16);
+#[cfg(any(feature = "critical-section-polyfill", target_has_atomic = "32"))]
+impl_option_spec!(NonZeroI32, NonZeroU32);
+#[cfg(any(feature = "critical-section-polyfill", target_has_atomic = "64"))]
+impl_option_spec!(NonZeroI64, NonZeroU64);
+#[cfg(any(feature = "critical-section-polyfill", target_has_atomic = "ptr"))]
+impl_option_spec!(NonZeroIsize, NonZeroUsize);
@@ -0,0 +1,89 @@
+use core::fmt;
+
+use crate::{Atomic, Ordering};
+
+///Struct, which can be packed into integer word to be stored within `AtomicPacked`.
+///
+///Normally implemented via `#[derive(AtomicPack)]` of `atomik-derive` crate.
+pub trait AtomicPack: Copy {
+    ///Integer type, which holds packed struct.
+    type Packed: Copy;
+
+    ///Packs struct into integer word.
+    fn pack(self) -> Self::Packed;
+    ///Unpacks struct from integer word.
+    fn unpack(packed: Self::Packed) -> Self;
+}
+
+///Atomic struct, stored as packed integer word.
+///
+///Allows to store structs, which have size of atomic integer but smaller alignment (e.g. `struct Rgba { r: u8, g: u8, b: u8, a: u8 }`).
+///Values are compared by packed representation.
+pub struct AtomicPacked<T: AtomicPack> {
+    inner: Atomic<T::Packed>,
+}
+
+impl<T: AtomicPack> AtomicPacked<T> {
+    #[inline]
+    ///Creates a new instance
+    pub fn new(value: T) -> Self {
+        Self {
+            inner: Atomic::new(value.pack()),
+        }
+    }
+
+    #[inline]
+    ///Returns atomic of packed representation.
+    pub fn as_packed(&self) -> &Atomic<T::Packed> {
+        &self.inner
+    }
+
+    #[inline]
+    ///Loads value.
+    pub fn load(&self, order: Ordering) -> T {
+        T::unpack(self.inner.load(order))
+    }
+
+    #[inline]
+    ///Stores value.
+    pub fn store(&self, val: T, order: Ordering) {
+        self.inner.store(val.pack(), order)
+    }
+
+    #[inline]
+    ///Stores value, returning the previous one.
+    pub fn swap(&self, val: T, order: Ordering) -> T {
+        T::unpack(self.inner.swap(val.pack(), order))
+    }
+
+    #[inline]
+    ///Stores `new` value if the current one is `current`.
+    ///
+    ///Values are compared by their packed representation.
+    pub fn compare_exchange(&self, current: T, new: T, success: Ordering, failure: Ordering) -> Result<T, T> {
+        match self.inner.compare_exchange(current.pack(), new.pack(), success, failure) {
+            Ok(prev) => Ok(T::unpack(prev)),
+            Err(prev) => Err(T::unpack(prev)),
+        }
+    }
+
+    #[inline]
+    ///Consumes self and returns the contained value.
+    pub fn into_inner(self) -> T {
+        T::unpack(self.inner.into_inner())
+    }
+}
+
+impl<T: AtomicPack + Default> Default for AtomicPacked<T> {
+    #[inline(always)]
+    fn default() -> Self {
+        Self::new(Default::default())
+    }
+}
+
+impl<T: AtomicPack + fmt::Debug> fmt::Debug for AtomicPacked<T> {
+    #[inline(always)]
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&self.load(Ordering::Relaxed), fmt)
+    }
+}
@@ -0,0 +1,52 @@
+use crate::{Atomic, Ordering};
+
+#[repr(C, align(8))]
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+///Pair of `u32` aligned to be stored within single 64bit word.
+///
+///Plain `(u32, u32)` has alignment of `u32`, so it cannot be used with `Atomic` directly.
+///This wrapper can be used as `Atomic<Pair32>` (e.g. to store index together with version tag).
+pub struct Pair32(pub u32, pub u32);
+
+impl From<(u32, u32)> for Pair32 {
+    #[inline(always)]
+    fn from((first, second): (u32, u32)) -> Self {
+        Self(first, second)
+    }
+}
+
+impl From<Pair32> for (u32, u32) {
+    #[inline(always)]
+    fn from(pair: Pair32) -> Self {
+        (pair.0, pair.1)
+    }
+}
+
+impl Atomic<Pair32> {
+    #[inline]
+    ///Loads pair from the atomic.
+    ///
+    ///See `load` for details.
+    pub fn load_pair(&self, order: Ordering) -> (u32, u32) {
+        self.load(order).into()
+    }
+
+    #[inline]
+    ///Stores pair into the atomic.
+    ///
+    ///See `store` for details.
+    pub fn store_pair(&self, val: (u32, u32), order: Ordering) {
+        self.store(val.into(), order)
+    }
+
+    #[inline]
+    ///Stores pair into the atomic if the current value is the same as the `current` pair.
+    ///
+    ///See `compare_exchange` for details.
+    pub fn compare_exchange_pair(&self, current: (u32, u32), new: (u32, u32), success: Ordering, failure: Ordering) -> Result<(u32, u32), (u32, u32)> {
+        match self.compare_exchange(current.into(), new.into(), success, failure) {
+            Ok(prev) => Ok(prev.into()),
+            Err(prev) => Err(prev.into()),
+        }
+    }
+}
@@ -0,0 +1,120 @@
+use core::ptr;
+
+use crate::{failure_ordering, Atomic, Ordering};
+
+impl<T> Atomic<*mut T> {
+    #[inline]
+    ///Loads pointer and checks whether it is null.
+    pub fn is_null(&self, order: Ordering) -> bool {
+        self.load(order).is_null()
+    }
+
+    #[inline]
+    ///Takes pointer out, leaving null in its place.
+    ///
+    ///Returns `None` if pointer was already null.
+    pub fn take_if_non_null(&self, order: Ordering) -> Option<*mut T> {
+        let ptr = self.swap(ptr::null_mut(), order);
+        if ptr.is_null() {
+            None
+        } else {
+            Some(ptr)
+        }
+    }
+
+    #[inline]
+    ///Stores `ptr` only if currently stored pointer is null.
+    ///
+    ///Returns `true` if `ptr` has been stored.
+    ///
+    ///Failure ordering is derived from `order` as the strongest one allowed.
+    pub fn set_if_null(&self, ptr: *mut T, order: Ordering) -> bool {
+        self.compare_exchange(ptr::null_mut(), ptr, order, failure_ordering(order)).is_ok()
+    }
+
+    #[inline]
+    ///Publishes `ptr` if atomic is still null, returning whether this call did the publish.
+    ///
+    ///Null is sentinel of not yet published pointer, hence `ptr` must not be null.
+    ///Once pointer is published, it is cheap no-op, consisting of single relaxed load.
+    ///Publishing is done via `compare_exchange` with `order`, which should be `Release` (or stronger) to publish pointee.
+    ///Published pointer keeps its provenance, so readers can dereference it after `Acquire` load.
+    pub fn publish_once(&self, ptr: *mut T, order: Ordering) -> bool {
+        debug_assert!(!ptr.is_null(), "Atomic: cannot publish null sentinel");
+        self.load(Ordering::Relaxed).is_null() && self.compare_exchange(ptr::null_mut(), ptr, order, Ordering::Relaxed).is_ok()
+    }
+
+    #[inline]
+    ///Loads pointer and passes it to `f` for dereferencing, returning its result.
+    ///
+    ///With `Acquire` (or stronger) `order`, `f` sees everything written before pointer has been stored with `Release`.
+    ///This makes dependency between pointer load and subsequent reads through it explicit.
+    pub fn load_then<R, F: FnOnce(*mut T) -> R>(&self, order: Ordering, f: F) -> R {
+        f(self.load(order))
+    }
+}
+
+impl<T> Atomic<*const T> {
+    #[inline]
+    ///Loads pointer and passes it to `f` for dereferencing, returning its result.
+    ///
+    ///With `Acquire` (or stronger) `order`, `f` sees everything written before pointer has been stored with `Release`.
+    ///This makes dependency between pointer load and subsequent reads through it explicit.
+    pub fn load_then<R, F: FnOnce(*const T) -> R>(&self, order: Ordering, f: F) -> R {
+        f(self.load(order))
+    }
+}
+
+impl<T> Atomic<ptr::NonNull<T>> {
+    #[inline(always)]
+    //`NonNull<T>` has the same layout as `*mut T`, so raw view allows to verify values before they become `NonNull`
+    //Both are pointer sized, hence go through `AtomicPtr` and keep provenance of stored pointer
+    fn as_raw(&self) -> &Atomic<*mut T> {
+        unsafe {
+            &*(self as *const Self as *const Atomic<*mut T>)
+        }
+    }
+
+    #[inline(always)]
+    fn to_non_null(ptr: *mut T) -> ptr::NonNull<T> {
+        debug_assert!(!ptr.is_null(), "Atomic<NonNull<T>>: observed null");
+        unsafe {
+            ptr::NonNull::new_unchecked(ptr)
+        }
+    }
+
+    #[inline]
+    ///Loads non-null pointer.
+    ///
+    ///Same as `load`, but in debug mode verifies that loaded pointer is not null.
+    pub fn load_non_null(&self, order: Ordering) -> ptr::NonNull<T> {
+        Self::to_non_null(self.as_raw().load(order))
+    }
+
+    #[inline]
+    ///Stores non-null pointer.
+    ///
+    ///Same as `store`, documenting that only `NonNull` can be stored, hence atomic can never contain null.
+    pub fn store_non_null(&self, ptr: ptr::NonNull<T>, order: Ordering) {
+        self.store(ptr, order)
+    }
+
+    #[inline]
+    ///Stores non-null pointer, returning previous one.
+    ///
+    ///Same as `swap`, but in debug mode verifies that previous pointer is not null.
+    pub fn swap_non_null(&self, ptr: ptr::NonNull<T>, order: Ordering) -> ptr::NonNull<T> {
+        Self::to_non_null(self.as_raw().swap(ptr.as_ptr(), order))
+    }
+
+    #[inline]
+    ///Stores `new` pointer if the current pointer is `current`.
+    ///
+    ///Same as `compare_exchange`, but in debug mode verifies that returned pointer is not null.
+    pub fn compare_exchange_non_null(&self, current: ptr::NonNull<T>, new: ptr::NonNull<T>, success: Ordering, failure: Ordering) -> Result<ptr::NonNull<T>, ptr::NonNull<T>> {
+        match self.as_raw().compare_exchange(current.as_ptr(), new.as_ptr(), success, failure) {
+            Ok(ptr) => Ok(Self::to_non_null(ptr)),
+            Err(ptr) => Err(Self::to_non_null(ptr)),
+        }
+    }
+}
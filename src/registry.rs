@@ -0,0 +1,60 @@
+//!Global registry of atomics for runtime introspection.
+
+use core::fmt;
+use std::boxed::Box;
+use std::sync::Mutex;
+use std::vec::Vec;
+
+use crate::{Atomic, Ordering};
+
+struct Entry {
+    name: &'static str,
+    addr: usize,
+    write: fn(usize, &mut dyn fmt::Write) -> fmt::Result,
+}
+
+static REGISTRY: Mutex<Vec<Entry>> = Mutex::new(Vec::new());
+
+fn write_value<T: Copy + fmt::Debug>(addr: usize, out: &mut dyn fmt::Write) -> fmt::Result {
+    //Registered atomics are leaked, so address is always valid
+    let atomic = unsafe {
+        &*(addr as *const Atomic<T>)
+    };
+    write!(out, "{:?}", atomic.load(Ordering::Relaxed))
+}
+
+impl<T: Copy + fmt::Debug + Send + 'static> Atomic<T> {
+    ///Creates a new instance with static lifetime, registering it under `name` within global registry.
+    ///
+    ///Intended for debug tooling only, as atomic is leaked to outlive registry.
+    pub fn new_registered(value: T, name: &'static str) -> &'static Self {
+        let atomic: &'static Self = Box::leak(Box::new(Self::new(value)));
+        let entry = Entry {
+            name,
+            addr: atomic as *const Self as usize,
+            write: write_value::<T>,
+        };
+        REGISTRY.lock().unwrap_or_else(|error| error.into_inner()).push(entry);
+        atomic
+    }
+}
+
+///Writes name and current value of each registered atomic into `out`, one per line.
+///
+///Values are loaded with `Relaxed` ordering.
+pub fn write_registry(out: &mut dyn fmt::Write) -> fmt::Result {
+    let registry = REGISTRY.lock().unwrap_or_else(|error| error.into_inner());
+    for entry in registry.iter() {
+        write!(out, "{}=", entry.name)?;
+        (entry.write)(entry.addr, out)?;
+        out.write_str("\n")?;
+    }
+    Ok(())
+}
+
+///Prints name and current value of each registered atomic to stdout.
+pub fn dump_registry() {
+    let mut out = std::string::String::new();
+    let _ = write_registry(&mut out);
+    std::print!("{}", out);
+}
@@ -0,0 +1,175 @@
+use core::{fmt, mem, ptr};
+use core::cell::UnsafeCell;
+
+use crate::{atomic, effective_ordering, Atomic, Ordering};
+
+///Sequence lock, allowing to share value of arbitrary size.
+///
+///Writers are serialized via version counter, which is odd while write is in progress.
+///Readers never block writers, instead they retry until they observe the same even version before and after reading value.
+///
+///Note that reader may copy value while it is being written, in which case copy is discarded.
+///This is the usual trade-off of sequence locks, hence `T` is required to be `Copy`.
+///
+///To avoid data race, value is copied by atomic words: `u32` when size and alignment of `T` allow it, otherwise bytes.
+///Hence `T` must implement `NoPadding`.
+pub struct SeqLock<T> {
+    version: Atomic<u32>,
+    data: UnsafeCell<T>,
+}
+
+///Type without padding bytes, which can be copied as sequence of integers.
+///
+///# Safety
+///
+///Every byte of `Self` must be initialized, i.e. `Self` must not contain padding or `MaybeUninit` fields.
+///Pointers are not implemented, as copying them as integers loses provenance.
+pub unsafe trait NoPadding: Copy {}
+
+macro_rules! impl_no_padding {
+    ($($ty:ty),*) => {$(
+        unsafe impl NoPadding for $ty {}
+    )*};
+}
+
+impl_no_padding!(bool, char, u8, i8, u16, i16, u32, i32, u64, i64, u128, i128, usize, isize, f32, f64);
+
+unsafe impl<T: NoPadding, const N: usize> NoPadding for [T; N] {}
+
+unsafe impl<T: Copy + Send> Sync for SeqLock<T> {}
+
+impl<T: NoPadding + Default> Default for SeqLock<T> {
+    #[inline(always)]
+    fn default() -> Self {
+        Self::new(Default::default())
+    }
+}
+
+impl<T: NoPadding> SeqLock<T> {
+    #[inline]
+    ///Creates a new instance
+    pub const fn new(value: T) -> Self {
+        Self {
+            version: Atomic::new(0),
+            data: UnsafeCell::new(value),
+        }
+    }
+}
+
+//Value is copied by `u32` when possible, as it requires less atomic operations than copying bytes.
+#[inline(always)]
+fn is_word_copy<T>() -> bool {
+    mem::size_of::<T>() & (mem::size_of::<u32>() - 1) == 0 && mem::align_of::<T>() >= mem::align_of::<Atomic<u32>>()
+}
+
+unsafe fn load_words<W: Copy>(src: *const Atomic<W>, dst: *mut W, len: usize) {
+    for idx in 0..len {
+        ptr::write(dst.add(idx), (*src.add(idx)).load(Ordering::Relaxed));
+    }
+}
+
+unsafe fn store_words<W: Copy>(dst: *const Atomic<W>, src: *const W, len: usize) {
+    for idx in 0..len {
+        (*dst.add(idx)).store(ptr::read(src.add(idx)), Ordering::Relaxed);
+    }
+}
+
+//Returned copy might be torn, so it is only valid after version is checked.
+unsafe fn atomic_read<T: NoPadding>(src: *const T) -> mem::MaybeUninit<T> {
+    let mut value = mem::MaybeUninit::<T>::uninit();
+    if is_word_copy::<T>() {
+        load_words(src as *const Atomic<u32>, value.as_mut_ptr() as *mut u32, mem::size_of::<T>() / mem::size_of::<u32>());
+    } else {
+        load_words(src as *const Atomic<u8>, value.as_mut_ptr() as *mut u8, mem::size_of::<T>());
+    }
+    value
+}
+
+unsafe fn atomic_write<T: NoPadding>(dst: *mut T, value: T) {
+    if is_word_copy::<T>() {
+        store_words(dst as *const Atomic<u32>, &value as *const T as *const u32, mem::size_of::<T>() / mem::size_of::<u32>());
+    } else {
+        store_words(dst as *const Atomic<u8>, &value as *const T as *const u8, mem::size_of::<T>());
+    }
+}
+
+impl<T: NoPadding> SeqLock<T> {
+    //Acquires write access, returning version prior to the write.
+    fn lock(&self) -> u32 {
+        loop {
+            let version = self.version.load(Ordering::Relaxed);
+            if version & 1 == 0 && self.version.compare_exchange_weak(version, version.wrapping_add(1), Ordering::Acquire, Ordering::Relaxed).is_ok() {
+                //Make sure readers cannot observe data write before odd version
+                atomic::fence(effective_ordering(Ordering::Release));
+                return version;
+            }
+            core::hint::spin_loop();
+        }
+    }
+
+    ///Returns a mutable reference to the underlying value.
+    ///
+    ///This is safe because the mutable reference guarantees that no other threads are concurrently accessing the data.
+    #[inline]
+    pub fn get_mut(&mut self) -> &mut T {
+        self.data.get_mut()
+    }
+
+    ///Consumes the lock and returns the contained value.
+    #[inline]
+    pub fn into_inner(self) -> T {
+        self.data.into_inner()
+    }
+
+    ///Reads consistent copy of the value, retrying while write is in progress.
+    pub fn read(&self) -> T {
+        loop {
+            let version = self.version.load(Ordering::Acquire);
+            if version & 1 == 0 {
+                let value = unsafe {
+                    atomic_read(self.data.get())
+                };
+                atomic::fence(effective_ordering(Ordering::Acquire));
+                if self.version.load(Ordering::Relaxed) == version {
+                    return unsafe {
+                        value.assume_init()
+                    };
+                }
+            }
+            core::hint::spin_loop();
+        }
+    }
+
+    ///Writes new value, waiting for other writers to finish.
+    pub fn write(&self, value: T) {
+        let version = self.lock();
+        unsafe {
+            atomic_write(self.data.get(), value);
+        }
+        self.version.store(version.wrapping_add(2), Ordering::Release);
+    }
+
+    ///Replaces value with result of `cb`, returning the previous value.
+    ///
+    ///Other writers wait until update is finished, so `cb` should be short.
+    pub fn update<F: FnOnce(T) -> T>(&self, cb: F) -> T {
+        let version = self.lock();
+        //Only writer can modify value, so it is fine to read while holding lock
+        let prev = unsafe {
+            ptr::read(self.data.get())
+        };
+        let value = cb(prev);
+        unsafe {
+            atomic_write(self.data.get(), value);
+        }
+        self.version.store(version.wrapping_add(2), Ordering::Release);
+        prev
+    }
+}
+
+impl<T: NoPadding + fmt::Debug> fmt::Debug for SeqLock<T> {
+    #[inline(always)]
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&self.read(), fmt)
+    }
+}
@@ -0,0 +1,71 @@
+use core::fmt;
+
+use crate::{Atomic, Ordering};
+
+///Atomic, which assigns monotonically increasing sequence number to every load.
+///
+///Sequence number orders loads of this particular atomic, allowing to reason about order of own observations (e.g. when debugging readers of cache).
+///Note that sequence is incremented separately from the value load, hence it doesn't order loads relative to stores.
+pub struct SequencedAtomic<T> {
+    value: Atomic<T>,
+    seq: Atomic<u64>,
+}
+
+impl<T> SequencedAtomic<T> {
+    #[inline]
+    ///Creates a new instance
+    pub const fn new(value: T) -> Self {
+        Self {
+            value: Atomic::new(value),
+            seq: Atomic::new(0),
+        }
+    }
+
+    #[inline(always)]
+    ///Returns underlying atomic, which can be accessed without sequencing.
+    pub fn as_atomic(&self) -> &Atomic<T> {
+        &self.value
+    }
+}
+
+impl<T: Copy> SequencedAtomic<T> {
+    #[inline]
+    ///Loads value, returning it together with sequence number of this load.
+    ///
+    ///Sequence number starts with zero and is incremented by every `load_seq`.
+    pub fn load_seq(&self, order: Ordering) -> (T, u64) {
+        let value = self.value.load(order);
+        (value, self.seq.fetch_add(1, Ordering::Relaxed))
+    }
+
+    #[inline]
+    ///Stores value.
+    pub fn store(&self, val: T, order: Ordering) {
+        self.value.store(val, order)
+    }
+
+    #[inline]
+    ///Returns number of performed `load_seq`.
+    pub fn sequence(&self) -> u64 {
+        self.seq.load(Ordering::Relaxed)
+    }
+
+    #[inline]
+    ///Consumes self and returns the contained value.
+    pub fn into_inner(self) -> T {
+        self.value.into_inner()
+    }
+}
+
+impl<T: Copy + Default> Default for SequencedAtomic<T> {
+    #[inline(always)]
+    fn default() -> Self {
+        Self::new(Default::default())
+    }
+}
+
+impl<T: Copy + fmt::Debug> fmt::Debug for SequencedAtomic<T> {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt.debug_struct("SequencedAtomic").field("value", &self.value.load(Ordering::Relaxed)).field("seq", &self.sequence()).finish()
+    }
+}
@@ -0,0 +1,71 @@
+use core::fmt;
+
+use crate::{Atomic, Ordering};
+
+#[repr(align(64))]
+//Aligns value to separate cache line, avoiding false sharing between shards
+struct CachePadded<T>(T);
+
+///Counter sharded across `N` cache lines, reducing contention of concurrent increments.
+///
+///Shard is selected by hashing address on the caller's stack, as each thread has own stack.
+///This is only a hint: multiple threads may use the same shard, which affects only performance.
+///
+///Reading counter requires loading all shards, so it is intended for write heavy counters.
+pub struct ShardedCounter<const N: usize> {
+    shards: [CachePadded<Atomic<u64>>; N],
+}
+
+impl<const N: usize> ShardedCounter<N> {
+    //Only used to initialize array, as atomic is not `Copy`
+    #[allow(clippy::declare_interior_mutable_const)]
+    const SHARD: CachePadded<Atomic<u64>> = CachePadded(Atomic::new(0));
+    const IS_VALID: () = assert!(N > 0, "ShardedCounter: N must be greater than 0");
+
+    #[inline]
+    ///Creates a new instance with all shards set to zero.
+    pub const fn new() -> Self {
+        let () = Self::IS_VALID;
+        Self {
+            shards: [Self::SHARD; N],
+        }
+    }
+
+    #[inline(always)]
+    fn shard(&self) -> &Atomic<u64> {
+        let marker = 0u8;
+        let addr = &marker as *const u8 as usize as u64;
+        //Fibonacci hashing to spread stack addresses, which usually differ only in higher bits
+        let hash = addr.wrapping_mul(0x9E3779B97F4A7C15) >> 32;
+        &self.shards[hash as usize % N].0
+    }
+
+    #[inline]
+    ///Adds `val` to the counter.
+    ///
+    ///Counter wraps around on overflow.
+    pub fn add(&self, val: u64, order: Ordering) {
+        self.shard().fetch_add(val, order);
+    }
+
+    ///Loads all shards, returning their sum.
+    ///
+    ///Note that it is not atomic snapshot, as each shard is loaded individually.
+    pub fn sum(&self, order: Ordering) -> u64 {
+        self.shards.iter().fold(0, |acc, shard| acc.wrapping_add(shard.0.load(order)))
+    }
+}
+
+impl<const N: usize> Default for ShardedCounter<N> {
+    #[inline(always)]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<const N: usize> fmt::Debug for ShardedCounter<N> {
+    #[inline(always)]
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&self.sum(Ordering::Relaxed), fmt)
+    }
+}
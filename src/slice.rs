@@ -0,0 +1,91 @@
+use core::mem;
+
+use crate::{failure_ordering, Atomic, Ordering};
+
+#[cfg(any(feature = "critical-section-polyfill", target_has_atomic = "32"))]
+///Loads every element of `data` and xors them together.
+///
+///This is cheap checksum to detect torn updates across group of atomics.
+///
+///Note that it is not atomic snapshot of `data`, as each element is loaded individually.
+pub fn xor_fold(data: &[Atomic<u32>], order: Ordering) -> u32 {
+    data.iter().fold(0, |acc, elem| acc ^ elem.load(order))
+}
+
+#[cfg(any(feature = "critical-section-polyfill", target_has_atomic = "32"))]
+///Increments histogram bucket at `idx`, returning its previous count.
+///
+///Returns `None` if `idx` is out of bounds.
+///
+///Bucket saturates at `u32::MAX`, instead of wrapping to zero.
+pub fn record(buckets: &[Atomic<u32>], idx: usize, order: Ordering) -> Option<u32> {
+    let bucket = buckets.get(idx)?;
+    let result = bucket.fetch_update(order, failure_ordering(order), |count| count.checked_add(1));
+    match result {
+        Ok(count) => Some(count),
+        Err(count) => Some(count),
+    }
+}
+
+impl<T: Copy> Atomic<T> {
+    #[inline]
+    ///Loads every element of `arr`, returning array of loaded values.
+    ///
+    ///Note that it is not atomic snapshot of `arr`, as each element is loaded individually.
+    pub fn snapshot<const N: usize>(arr: &[Atomic<T>; N], order: Ordering) -> [T; N] {
+        core::array::from_fn(|idx| arr[idx].load(order))
+    }
+
+    #[inline]
+    ///Stores each value of `vals` into corresponding element of `arr`.
+    ///
+    ///Note that it is not atomic as whole, as each element is stored individually.
+    pub fn write_all<const N: usize>(arr: &[Atomic<T>; N], vals: &[T; N], order: Ordering) {
+        for (elem, val) in arr.iter().zip(vals.iter()) {
+            elem.store(*val, order);
+        }
+    }
+
+    #[inline]
+    ///Returns mutable view of `arr` as plain values.
+    ///
+    ///This is safe because the mutable reference guarantees that no other threads are concurrently accessing `arr`.
+    ///Useful for bulk initialization (e.g. using SIMD) before sharing `arr` with other threads.
+    pub fn as_mut_slice<const N: usize>(arr: &mut [Atomic<T>; N]) -> &mut [T; N] {
+        //Atomic is transparent wrapper over T, so layout of arrays is the same
+        debug_assert_eq!(mem::size_of::<[Atomic<T>; N]>(), mem::size_of::<[T; N]>());
+        debug_assert_eq!(mem::align_of::<[Atomic<T>; N]>(), mem::align_of::<[T; N]>());
+        unsafe {
+            &mut *(arr as *mut [Atomic<T>; N] as *mut [T; N])
+        }
+    }
+
+    #[inline]
+    ///Sets every element of `arr` to `val`.
+    ///
+    ///Uses `get_mut` per element, hence no atomic instructions are involved, as mutable reference guarantees exclusive access.
+    ///Suitable to reset `arr` between single-threaded phases.
+    pub fn reset_all<const N: usize>(arr: &mut [Atomic<T>; N], val: T) {
+        for elem in arr.iter_mut() {
+            *elem.get_mut() = val;
+        }
+    }
+}
+
+impl<T: Copy + PartialEq> Atomic<T> {
+    #[inline]
+    ///Loads every element of `current`, yielding `(index, value)` only for elements, which differ from `prev`.
+    ///
+    ///Elements are compared up to the shortest of `current` and `prev`.
+    ///Note that it is not atomic snapshot of `current`, as each element is loaded individually.
+    pub fn diff_snapshot<'a>(current: &'a [Atomic<T>], prev: &'a [T], order: Ordering) -> impl Iterator<Item = (usize, T)> + 'a {
+        current.iter().zip(prev.iter()).enumerate().filter_map(move |(idx, (elem, prev))| {
+            let value = elem.load(order);
+            if value == *prev {
+                None
+            } else {
+                Some((idx, value))
+            }
+        })
+    }
+}
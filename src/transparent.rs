@@ -0,0 +1,190 @@
+use crate::{Atomic, Ordering};
+
+///Newtype over integer, which can be stored within `Atomic` with integer operations of the inner type.
+///
+///Normally implemented via `impl_atomic_transparent!`.
+///
+///# Safety
+///
+///`Self` must have the same layout as `Inner` and every bit pattern of `Inner` must be valid `Self` (i.e. `#[repr(transparent)]` tuple struct with single `Inner` field).
+pub unsafe trait AtomicTransparent: Copy {
+    ///Integer type, which is wrapped by `Self`.
+    type Inner: AtomicInteger;
+
+    ///Wraps `inner` value.
+    fn from_inner(inner: Self::Inner) -> Self;
+    ///Unwraps inner value.
+    fn into_inner(self) -> Self::Inner;
+}
+
+///Integer, which provides arithmetic operations for `Atomic` of `AtomicTransparent` newtype.
+pub trait AtomicInteger: Copy {
+    #[doc(hidden)]
+    fn fetch_add(atomic: &Atomic<Self>, val: Self, order: Ordering) -> Self;
+    #[doc(hidden)]
+    fn fetch_sub(atomic: &Atomic<Self>, val: Self, order: Ordering) -> Self;
+    #[doc(hidden)]
+    fn fetch_and(atomic: &Atomic<Self>, val: Self, order: Ordering) -> Self;
+    #[doc(hidden)]
+    fn fetch_or(atomic: &Atomic<Self>, val: Self, order: Ordering) -> Self;
+    #[doc(hidden)]
+    fn fetch_xor(atomic: &Atomic<Self>, val: Self, order: Ordering) -> Self;
+    #[doc(hidden)]
+    fn fetch_min(atomic: &Atomic<Self>, val: Self, order: Ordering) -> Self;
+    #[doc(hidden)]
+    fn fetch_max(atomic: &Atomic<Self>, val: Self, order: Ordering) -> Self;
+}
+
+macro_rules! impl_atomic_integer {
+    ($($ty:ident),*) => {$(
+        impl AtomicInteger for $ty {
+            #[inline(always)]
+            fn fetch_add(atomic: &Atomic<Self>, val: Self, order: Ordering) -> Self {
+                atomic.fetch_add(val, order)
+            }
+            #[inline(always)]
+            fn fetch_sub(atomic: &Atomic<Self>, val: Self, order: Ordering) -> Self {
+                atomic.fetch_sub(val, order)
+            }
+            #[inline(always)]
+            fn fetch_and(atomic: &Atomic<Self>, val: Self, order: Ordering) -> Self {
+                atomic.fetch_and(val, order)
+            }
+            #[inline(always)]
+            fn fetch_or(atomic: &Atomic<Self>, val: Self, order: Ordering) -> Self {
+                atomic.fetch_or(val, order)
+            }
+            #[inline(always)]
+            fn fetch_xor(atomic: &Atomic<Self>, val: Self, order: Ordering) -> Self {
+                atomic.fetch_xor(val, order)
+            }
+            #[inline(always)]
+            fn fetch_min(atomic: &Atomic<Self>, val: Self, order: Ordering) -> Self {
+                atomic.fetch_min(val, order)
+            }
+            #[inline(always)]
+            fn fetch_max(atomic: &Atomic<Self>, val: Self, order: Ordering) -> Self {
+                atomic.fetch_max(val, order)
+            }
+        }
+    )*};
+}
+
+#[cfg(any(feature = "critical-section-polyfill", target_has_atomic = "8"))]
+impl_atomic_integer!(i8, u8);
+#[cfg(any(feature = "critical-section-polyfill", target_has_atomic = "16"))]
+impl_atomic_integer!(i16, u16);
+#[cfg(any(feature = "critical-section-polyfill", target_has_atomic = "32"))]
+impl_atomic_integer!(i32, u32);
+#[cfg(any(feature = "critical-section-polyfill", target_has_atomic = "64"))]
+impl_atomic_integer!(i64, u64);
+
+#[cfg(
+    any(
+        feature = "critical-section-polyfill",
+        all(target_has_atomic = "64", target_pointer_width = "64"),
+        all(target_has_atomic = "32", target_pointer_width = "32"),
+        all(target_has_atomic = "16", target_pointer_width = "16"),
+    )
+)]
+impl_atomic_integer!(isize, usize);
+
+#[macro_export]
+///Implements `AtomicTransparent` for tuple struct `$name`, wrapping single integer field of `$inner` type.
+///
+///Fails to compile if `$name` has different size or alignment than `$inner`.
+///
+///```
+///#[derive(Copy, Clone, Debug, PartialEq)]
+///#[repr(transparent)]
+///struct Handle(u32);
+///
+///atomik::impl_atomic_transparent!(Handle => u32);
+///
+///let handle = atomik::Atomic::new(Handle(1));
+///assert_eq!(handle.fetch_add(Handle(2), atomik::Ordering::Relaxed), Handle(1));
+///```
+macro_rules! impl_atomic_transparent {
+    ($name:ident => $inner:ty) => {
+        const _: () = assert!(
+            ::core::mem::size_of::<$name>() == ::core::mem::size_of::<$inner>() && ::core::mem::align_of::<$name>() == ::core::mem::align_of::<$inner>(),
+            "AtomicTransparent: newtype must have the same size and alignment as inner type"
+        );
+
+        //Constructor guarantees that newtype consists of single field of inner type, hence any inner value is valid
+        unsafe impl $crate::AtomicTransparent for $name {
+            type Inner = $inner;
+
+            #[inline(always)]
+            fn from_inner(inner: Self::Inner) -> Self {
+                $name(inner)
+            }
+
+            #[inline(always)]
+            fn into_inner(self) -> Self::Inner {
+                self.0
+            }
+        }
+    };
+}
+
+impl<T: AtomicTransparent> Atomic<T> {
+    #[inline(always)]
+    ///Returns atomic of the inner integer type, sharing storage with `self`.
+    pub fn as_inner(&self) -> &Atomic<T::Inner> {
+        //AtomicTransparent guarantees that Inner has the same layout
+        unsafe {
+            &*(self as *const Self as *const Atomic<T::Inner>)
+        }
+    }
+
+    #[inline]
+    ///Adds to the current value, returning the previous value.
+    ///
+    ///Same as `fetch_add` of the inner type.
+    pub fn fetch_add(&self, val: T, order: Ordering) -> T {
+        T::from_inner(T::Inner::fetch_add(self.as_inner(), val.into_inner(), order))
+    }
+
+    #[inline]
+    ///Subtract from the current value, returning the previous value.
+    ///
+    ///Same as `fetch_sub` of the inner type.
+    pub fn fetch_sub(&self, val: T, order: Ordering) -> T {
+        T::from_inner(T::Inner::fetch_sub(self.as_inner(), val.into_inner(), order))
+    }
+
+    #[inline]
+    ///Bitwise and with the current value, returning the previous value.
+    pub fn fetch_and(&self, val: T, order: Ordering) -> T {
+        T::from_inner(T::Inner::fetch_and(self.as_inner(), val.into_inner(), order))
+    }
+
+    #[inline]
+    ///Bitwise or with the current value, returning the previous value.
+    pub fn fetch_or(&self, val: T, order: Ordering) -> T {
+        T::from_inner(T::Inner::fetch_or(self.as_inner(), val.into_inner(), order))
+    }
+
+    #[inline]
+    ///Bitwise xor with the current value, returning the previous value.
+    pub fn fetch_xor(&self, val: T, order: Ordering) -> T {
+        T::from_inner(T::Inner::fetch_xor(self.as_inner(), val.into_inner(), order))
+    }
+
+    #[inline]
+    ///Minimum with the current value, returning the previous value.
+    ///
+    ///Comparison is performed according to the inner type.
+    pub fn fetch_min(&self, val: T, order: Ordering) -> T {
+        T::from_inner(T::Inner::fetch_min(self.as_inner(), val.into_inner(), order))
+    }
+
+    #[inline]
+    ///Maximum with the current value, returning the previous value.
+    ///
+    ///Comparison is performed according to the inner type.
+    pub fn fetch_max(&self, val: T, order: Ordering) -> T {
+        T::from_inner(T::Inner::fetch_max(self.as_inner(), val.into_inner(), order))
+    }
+}
@@ -0,0 +1,101 @@
+use crate::{failure_ordering, Atomic, Ordering};
+
+///Builder of `fetch_update`, composing operations into single closure.
+///
+///Created via `update_builder` of integer atomics, operations are applied in order of calls within single CAS loop.
+pub struct Update<'a, T, F> {
+    atomic: &'a Atomic<T>,
+    op: F,
+}
+
+impl<'a, T: Copy, F: Fn(T) -> T> Update<'a, T, F> {
+    #[inline]
+    ///Adds arbitrary operation `map`, which is applied after already added ones.
+    pub fn map<M: Fn(T) -> T>(self, map: M) -> Update<'a, T, impl Fn(T) -> T> {
+        let op = self.op;
+        Update {
+            atomic: self.atomic,
+            op: move |value| map(op(value)),
+        }
+    }
+
+    #[inline]
+    ///Applies operations to the atomic via `fetch_update`, returning the previous value.
+    ///
+    ///Failure ordering is derived from `order` as the strongest one allowed.
+    pub fn apply(self, order: Ordering) -> T {
+        let op = self.op;
+        match self.atomic.fetch_update(order, failure_ordering(order), |value| Some(op(value))) {
+            Ok(prev) => prev,
+            Err(prev) => prev,
+        }
+    }
+}
+
+macro_rules! impl_update {
+    ($($ty:ident),*) => {$(
+        impl Atomic<$ty> {
+            #[inline]
+            ///Creates builder of `fetch_update`, allowing to compose operations declaratively.
+            ///
+            ///For example `atomic.update_builder().add(5).min(100).apply(order)` adds 5, but clamps result to 100.
+            pub fn update_builder(&self) -> Update<'_, $ty, fn($ty) -> $ty> {
+                fn identity(value: $ty) -> $ty {
+                    value
+                }
+
+                Update {
+                    atomic: self,
+                    op: identity,
+                }
+            }
+        }
+
+        //Names follow `fetch_*` methods, builder is not an operand of arithmetic operators
+        #[allow(clippy::should_implement_trait)]
+        impl<'a, F: Fn($ty) -> $ty> Update<'a, $ty, F> {
+            #[inline]
+            ///Adds `val`, wrapping around on overflow.
+            pub fn add(self, val: $ty) -> Update<'a, $ty, impl Fn($ty) -> $ty> {
+                self.map(move |value| value.wrapping_add(val))
+            }
+
+            #[inline]
+            ///Subtracts `val`, wrapping around on overflow.
+            pub fn sub(self, val: $ty) -> Update<'a, $ty, impl Fn($ty) -> $ty> {
+                self.map(move |value| value.wrapping_sub(val))
+            }
+
+            #[inline]
+            ///Takes minimum with `val`.
+            pub fn min(self, val: $ty) -> Update<'a, $ty, impl Fn($ty) -> $ty> {
+                self.map(move |value| core::cmp::min(value, val))
+            }
+
+            #[inline]
+            ///Takes maximum with `val`.
+            pub fn max(self, val: $ty) -> Update<'a, $ty, impl Fn($ty) -> $ty> {
+                self.map(move |value| core::cmp::max(value, val))
+            }
+        }
+    )*};
+}
+
+#[cfg(any(feature = "critical-section-polyfill", target_has_atomic = "8"))]
+impl_update!(i8, u8);
+#[cfg(any(feature = "critical-section-polyfill", target_has_atomic = "16"))]
+impl_update!(i16, u16);
+#[cfg(any(feature = "critical-section-polyfill", target_has_atomic = "32"))]
+impl_update!(i32, u32);
+#[cfg(any(feature = "critical-section-polyfill", target_has_atomic = "64"))]
+impl_update!(i64, u64);
+
+#[cfg(
+    any(
+        feature = "critical-section-polyfill",
+        all(target_has_atomic = "64", target_pointer_width = "64"),
+        all(target_has_atomic = "32", target_pointer_width = "32"),
+        all(target_has_atomic = "16", target_pointer_width = "16"),
+    )
+)]
+impl_update!(isize, usize);
@@ -0,0 +1,80 @@
+use core::fmt;
+
+use crate::{atomic, effective_ordering, Atomic, Ordering};
+
+///Value with version counter, allowing to double-check that value has not been changed while reading it.
+///
+///This is lightweight variant of `SeqLock` for `T`, which can be stored within `Atomic`.
+///Version is odd while write is in progress, and readers retry until they observe the same even version before and after reading value.
+///
+///Only single writer is expected: concurrent writes do not cause undefined behavior, but may leave version even while write is still in progress.
+pub struct Versioned<T> {
+    version: Atomic<u32>,
+    value: Atomic<T>,
+}
+
+impl<T: Copy + Default> Default for Versioned<T> {
+    #[inline(always)]
+    fn default() -> Self {
+        Self::new(Default::default())
+    }
+}
+
+impl<T> Versioned<T> {
+    #[inline]
+    ///Creates a new instance
+    pub const fn new(value: T) -> Self {
+        Self {
+            version: Atomic::new(0),
+            value: Atomic::new(value),
+        }
+    }
+}
+
+impl<T: Copy> Versioned<T> {
+    #[inline]
+    ///Returns current version.
+    ///
+    ///It is incremented by 2 on every write.
+    pub fn version(&self) -> u32 {
+        self.version.load(Ordering::Acquire)
+    }
+
+    ///Reads value, retrying until it is not changed while reading.
+    pub fn read(&self) -> T {
+        loop {
+            let version = self.version.load(Ordering::Acquire);
+            if version & 1 == 0 {
+                let value = self.value.load(Ordering::Relaxed);
+                atomic::fence(effective_ordering(Ordering::Acquire));
+                if self.version.load(Ordering::Relaxed) == version {
+                    return value;
+                }
+            }
+            core::hint::spin_loop();
+        }
+    }
+
+    ///Writes new value, bumping version around the store.
+    pub fn write(&self, value: T) {
+        let version = self.version.load(Ordering::Relaxed);
+        self.version.store(version.wrapping_add(1), Ordering::Relaxed);
+        //Make sure readers cannot observe value before odd version
+        atomic::fence(effective_ordering(Ordering::Release));
+        self.value.store(value, Ordering::Relaxed);
+        self.version.store(version.wrapping_add(2), Ordering::Release);
+    }
+
+    #[inline]
+    ///Consumes self and returns the contained value.
+    pub fn into_inner(self) -> T {
+        self.value.into_inner()
+    }
+}
+
+impl<T: Copy + fmt::Debug> fmt::Debug for Versioned<T> {
+    #[inline(always)]
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&self.read(), fmt)
+    }
+}
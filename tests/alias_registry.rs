@@ -0,0 +1,52 @@
+#![cfg(all(feature = "debug-checks", feature = "std"))]
+
+use atomik::{Atomic, Ordering};
+
+//Registry is global, so each test uses own static buffer
+#[repr(align(8))]
+struct Buffer([u8; 16]);
+
+#[test]
+fn should_allow_same_width_at_the_same_address() {
+    static mut BUF: Buffer = Buffer([0; 16]);
+
+    let buf = unsafe {
+        &mut *core::ptr::addr_of_mut!(BUF.0)
+    };
+    Atomic::<u32>::from_bytes_mut(buf, 0).expect("aligned").store(1, Ordering::Relaxed);
+    Atomic::<u32>::from_bytes_mut(buf, 4).expect("aligned").store(2, Ordering::Relaxed);
+    let num = Atomic::<u32>::from_bytes_mut(buf, 0).expect("aligned");
+    assert_eq!(num.load(Ordering::Relaxed), 1);
+}
+
+#[test]
+#[cfg_attr(debug_assertions, should_panic(expected = "overlaps with atomic of 4 bytes"))]
+fn should_detect_overlap_of_different_width() {
+    static mut BUF: Buffer = Buffer([0; 16]);
+
+    //Overlapping views of the same memory, as if it is shared memory mapped twice by mistake
+    let ptr = unsafe {
+        core::ptr::addr_of_mut!(BUF.0) as *mut u8
+    };
+    let first = unsafe {
+        core::slice::from_raw_parts_mut(ptr, 16)
+    };
+    let second = unsafe {
+        core::slice::from_raw_parts_mut(ptr, 16)
+    };
+    let _num = Atomic::<u32>::from_bytes_mut(first, 4).expect("aligned");
+    Atomic::<u16>::from_bytes_mut(second, 6).expect("aligned");
+}
+
+#[test]
+fn should_reuse_memory_with_different_width_after_drop() {
+    static mut BUF: Buffer = Buffer([0; 16]);
+
+    let buf = unsafe {
+        &mut *core::ptr::addr_of_mut!(BUF.0)
+    };
+    let num = Atomic::<u32>::from_bytes_mut(buf, 8).expect("aligned");
+    num.store(7, Ordering::Relaxed);
+    drop(num);
+    Atomic::<u16>::from_bytes_mut(buf, 10).expect("aligned").store(1, Ordering::Relaxed);
+}
@@ -0,0 +1,71 @@
+#![cfg(feature = "arc")]
+
+use atomik::AtomicArc;
+
+use std::sync::Arc;
+use std::thread;
+
+#[test]
+fn should_swap_arc_with_correct_counts() {
+    let first = Arc::new(1u32);
+    let second = Arc::new(2u32);
+    let slot = AtomicArc::new(first.clone());
+    assert_eq!(Arc::strong_count(&first), 2);
+
+    let loaded = slot.load();
+    assert_eq!(*loaded, 1);
+    assert_eq!(Arc::strong_count(&first), 3);
+    drop(loaded);
+
+    let prev = slot.swap(second.clone());
+    assert!(Arc::ptr_eq(&prev, &first));
+    assert_eq!(Arc::strong_count(&second), 2);
+    drop(prev);
+    assert_eq!(Arc::strong_count(&first), 1);
+
+    slot.store(first.clone());
+    assert_eq!(Arc::strong_count(&second), 1);
+    assert_eq!(Arc::strong_count(&first), 2);
+
+    let inner = slot.into_inner();
+    assert!(Arc::ptr_eq(&inner, &first));
+    drop(inner);
+    assert_eq!(Arc::strong_count(&first), 1);
+}
+
+#[test]
+fn should_not_leak_arc_swapped_across_threads() {
+    //Miri is slow, but it is the one that verifies reclamation
+    const SWAPS: usize = if cfg!(miri) { 50 } else { 1_000 };
+
+    let values: Vec<_> = (0..4).map(Arc::new).collect();
+    let slot = Arc::new(AtomicArc::new(values[0].clone()));
+
+    let writers: Vec<_> = (0..2).map(|idx| {
+        let slot = slot.clone();
+        let values = values.clone();
+        thread::spawn(move || {
+            for swap in 0..SWAPS {
+                slot.store(values[(idx + swap) % values.len()].clone());
+            }
+        })
+    }).collect();
+    let readers: Vec<_> = (0..2).map(|_| {
+        let slot = slot.clone();
+        thread::spawn(move || {
+            for _ in 0..SWAPS {
+                let value = slot.load();
+                assert!(*value < 4);
+            }
+        })
+    }).collect();
+
+    for worker in writers.into_iter().chain(readers) {
+        worker.join().expect("worker to finish");
+    }
+
+    let last = Arc::try_unwrap(slot).expect("single owner").into_inner();
+    assert_eq!(Arc::strong_count(&last), 2);
+    drop(last);
+    assert!(values.iter().all(|value| Arc::strong_count(value) == 1));
+}
@@ -0,0 +1,94 @@
+#![cfg(feature = "async")]
+
+use atomik::{Atomic, AtomicFlag};
+use core::sync::atomic::Ordering;
+
+use core::future::Future;
+use core::pin::pin;
+use std::task;
+use std::sync::Arc;
+use std::thread::{self, Thread};
+
+struct ThreadWaker(Thread);
+
+struct NoopWaker;
+
+impl task::Wake for NoopWaker {
+    fn wake(self: Arc<Self>) {
+    }
+}
+
+impl task::Wake for ThreadWaker {
+    fn wake(self: Arc<Self>) {
+        self.0.unpark();
+    }
+}
+
+fn block_on<F: Future>(fut: F) -> F::Output {
+    let waker = task::Waker::from(Arc::new(ThreadWaker(thread::current())));
+    let mut ctx = task::Context::from_waker(&waker);
+    let mut fut = pin!(fut);
+    loop {
+        match fut.as_mut().poll(&mut ctx) {
+            task::Poll::Ready(result) => break result,
+            task::Poll::Pending => thread::park(),
+        }
+    }
+}
+
+#[test]
+fn should_complete_wait_when_flag_is_set() {
+    static FLAG: AtomicFlag = AtomicFlag::new(false);
+    assert!(!FLAG.is_set());
+
+    let setter = thread::spawn(|| {
+        thread::sleep(core::time::Duration::from_millis(10));
+        FLAG.set();
+    });
+    block_on(FLAG.wait());
+    assert!(FLAG.is_set());
+    setter.join().expect("setter to finish");
+
+    //Already set flag completes immediately
+    block_on(FLAG.wait());
+    assert!(FLAG.clear());
+    assert!(!FLAG.is_set());
+}
+
+#[test]
+fn should_not_complete_wait_until_flag_is_set() {
+    let flag = AtomicFlag::default();
+    let waker = task::Waker::from(Arc::new(NoopWaker));
+    let mut ctx = task::Context::from_waker(&waker);
+    let mut wait = pin!(flag.wait());
+
+    assert!(wait.as_mut().poll(&mut ctx).is_pending());
+    assert!(wait.as_mut().poll(&mut ctx).is_pending());
+    flag.set();
+    assert!(wait.as_mut().poll(&mut ctx).is_ready());
+}
+
+#[test]
+fn should_fetch_update_with_async_closure() {
+    static VALUE: Atomic<u32> = Atomic::new(1);
+    let mut calls = 0;
+
+    let result = block_on(VALUE.async_fetch_update(Ordering::AcqRel, Ordering::Acquire, |prev| {
+        calls += 1;
+        //Force contention on the first attempt
+        if calls == 1 {
+            VALUE.store(10, Ordering::Release);
+        }
+        async move {
+            Some(prev * 2)
+        }
+    }));
+    assert_eq!(result, Ok(10));
+    assert_eq!(calls, 2);
+    assert_eq!(VALUE.load(Ordering::Acquire), 20);
+
+    let result = block_on(VALUE.async_fetch_update(Ordering::AcqRel, Ordering::Acquire, |_| async {
+        None
+    }));
+    assert_eq!(result, Err(20));
+}
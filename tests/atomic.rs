@@ -1,4 +1,6 @@
-use atomik::{Atomic, Ordering};
+#![allow(clippy::legacy_numeric_constants, clippy::bool_assert_comparison)]
+
+use atomik::{Atomic, Ordering, Pair32, SeqLock, Versioned, xor_fold, record};
 
 #[test]
 fn atomic_bool() {
@@ -57,6 +59,28 @@ macro_rules! impl_test_unsigned {
         assert_eq!(NUM.fetch_update(Ordering::SeqCst, Ordering::SeqCst, |x| Some(x + 1)), Ok(7));
         assert_eq!(NUM.fetch_update(Ordering::SeqCst, Ordering::SeqCst, |x| Some(x + 1)), Ok(8));
         assert_eq!(NUM.load(Ordering::SeqCst), 9);
+
+        assert_eq!(NUM.fetch_update_relaxed(|_| None), NUM.fetch_update(Ordering::Relaxed, Ordering::Relaxed, |_| None));
+        assert_eq!(NUM.fetch_update_relaxed(|x| Some(x + 1)), Ok(9));
+        assert_eq!(NUM.fetch_update(Ordering::Relaxed, Ordering::Relaxed, |x| Some(x + 1)), Ok(10));
+        assert_eq!(NUM.load(Ordering::Relaxed), 11);
+
+        assert_eq!(NUM.fetch_max(5, Ordering::Relaxed), 11);
+        assert_eq!(NUM.load(Ordering::Relaxed), 11);
+        assert_eq!(NUM.fetch_max($ty::MAX, Ordering::Relaxed), 11);
+        assert_eq!(NUM.load(Ordering::Relaxed), $ty::MAX);
+        assert_eq!(NUM.fetch_max(0, Ordering::Relaxed), $ty::MAX);
+        assert_eq!(NUM.load(Ordering::Relaxed), $ty::MAX);
+
+        assert_eq!(NUM.fetch_min($ty::MAX, Ordering::Relaxed), $ty::MAX);
+        assert_eq!(NUM.load(Ordering::Relaxed), $ty::MAX);
+        assert_eq!(NUM.fetch_min(11, Ordering::Relaxed), $ty::MAX);
+        assert_eq!(NUM.load(Ordering::Relaxed), 11);
+        assert_eq!(NUM.fetch_min(0, Ordering::Relaxed), 11);
+        assert_eq!(NUM.load(Ordering::Relaxed), 0);
+        assert_eq!(NUM.fetch_max(0, Ordering::Relaxed), 0);
+        assert_eq!(NUM.fetch_min($ty::MAX, Ordering::Relaxed), 0);
+        assert_eq!(NUM.load(Ordering::Relaxed), 0);
     };
 }
 
@@ -85,6 +109,23 @@ macro_rules! impl_test_signed {
         assert_eq!(NUM.fetch_add(1, Ordering::Relaxed), $ty::max_value());
         assert_eq!(NUM.fetch_sub($ty::min_value(), Ordering::Relaxed), $ty::min_value());
 
+        NUM.store(3, Ordering::Relaxed);
+        assert_eq!(NUM.fetch_min(-5, Ordering::Relaxed), 3);
+        assert_eq!(NUM.load(Ordering::Relaxed), -5);
+        assert_eq!(NUM.fetch_min(-1, Ordering::Relaxed), -5);
+        assert_eq!(NUM.load(Ordering::Relaxed), -5);
+        assert_eq!(NUM.fetch_max(-7, Ordering::Relaxed), -5);
+        assert_eq!(NUM.load(Ordering::Relaxed), -5);
+        assert_eq!(NUM.fetch_max(2, Ordering::Relaxed), -5);
+        assert_eq!(NUM.load(Ordering::Relaxed), 2);
+        assert_eq!(NUM.fetch_min(0, Ordering::Relaxed), 2);
+        assert_eq!(NUM.fetch_max(-1, Ordering::Relaxed), 0);
+        assert_eq!(NUM.load(Ordering::Relaxed), 0);
+        assert_eq!(NUM.fetch_min($ty::min_value(), Ordering::Relaxed), 0);
+        assert_eq!(NUM.fetch_max($ty::max_value(), Ordering::Relaxed), $ty::min_value());
+        assert_eq!(NUM.fetch_min(-1, Ordering::Relaxed), $ty::max_value());
+        assert_eq!(NUM.load(Ordering::Relaxed), -1);
+
         NUM.store(0b101101, Ordering::Relaxed);
         assert_eq!(NUM.fetch_or(0b110011, Ordering::Relaxed), 0b101101);
 
@@ -105,6 +146,11 @@ macro_rules! impl_test_signed {
         assert_eq!(NUM.fetch_update(Ordering::SeqCst, Ordering::SeqCst, |x| Some(x + 1)), Ok(7));
         assert_eq!(NUM.fetch_update(Ordering::SeqCst, Ordering::SeqCst, |x| Some(x + 1)), Ok(8));
         assert_eq!(NUM.load(Ordering::SeqCst), 9);
+
+        assert_eq!(NUM.fetch_update_relaxed(|_| None), NUM.fetch_update(Ordering::Relaxed, Ordering::Relaxed, |_| None));
+        assert_eq!(NUM.fetch_update_relaxed(|x| Some(x + 1)), Ok(9));
+        assert_eq!(NUM.fetch_update(Ordering::Relaxed, Ordering::Relaxed, |x| Some(x + 1)), Ok(10));
+        assert_eq!(NUM.load(Ordering::Relaxed), 11);
     };
 }
 
@@ -133,6 +179,12 @@ fn should_check_methods_work_on_usize() {
     impl_test_unsigned!(usize);
 }
 
+#[cfg(all(feature = "atomic-128", target_has_atomic = "128"))]
+#[test]
+fn should_check_methods_work_on_u128() {
+    impl_test_unsigned!(u128);
+}
+
 #[test]
 fn should_check_methods_work_on_i8() {
     impl_test_signed!(i8);
@@ -157,3 +209,1534 @@ fn should_check_methods_work_on_i64() {
 fn should_check_methods_work_on_isize() {
     impl_test_signed!(isize);
 }
+
+#[cfg(all(feature = "atomic-128", target_has_atomic = "128"))]
+#[test]
+fn should_check_methods_work_on_i128() {
+    impl_test_signed!(i128);
+}
+
+#[repr(align(8))]
+struct AlignedBuf([u8; 16]);
+
+#[test]
+fn should_create_atomic_from_aligned_bytes() {
+    let mut buf = AlignedBuf([0; 16]);
+    buf.0[8..].copy_from_slice(&5u64.to_ne_bytes());
+
+    {
+        let num = Atomic::<u64>::from_bytes_mut(&mut buf.0, 8).expect("aligned offset");
+        assert_eq!(num.load(Ordering::Relaxed), 5);
+        num.store(10, Ordering::Relaxed);
+    }
+    assert_eq!(buf.0[8..], 10u64.to_ne_bytes());
+
+    let num = Atomic::<u32>::from_bytes_mut(&mut buf.0, 4).expect("aligned offset");
+    assert_eq!(num.fetch_add(1, Ordering::Relaxed), 0);
+    assert_eq!(num.load(Ordering::Relaxed), 1);
+}
+
+#[test]
+fn should_not_create_atomic_from_misaligned_bytes() {
+    let mut buf = AlignedBuf([0; 16]);
+    assert!(Atomic::<u64>::from_bytes_mut(&mut buf.0, 1).is_none());
+    assert!(Atomic::<u64>::from_bytes_mut(&mut buf.0, 4).is_none());
+    assert!(Atomic::<u16>::from_bytes_mut(&mut buf.0, 3).is_none());
+    assert!(Atomic::<u8>::from_bytes_mut(&mut buf.0, 3).is_some());
+}
+
+#[test]
+fn should_not_create_atomic_from_out_of_bounds_bytes() {
+    let mut buf = AlignedBuf([0; 16]);
+    assert!(Atomic::<u64>::from_bytes_mut(&mut buf.0, 16).is_none());
+    assert!(Atomic::<u64>::from_bytes_mut(&mut buf.0, 12).is_none());
+    assert!(Atomic::<u32>::from_bytes_mut(&mut buf.0, 16).is_none());
+    assert!(Atomic::<u64>::from_bytes_mut(&mut buf.0, usize::max_value()).is_none());
+}
+
+#[test]
+fn should_publish_and_consume_across_threads() {
+    static DATA: Atomic::<u32> = Atomic::new(0);
+    static READY: Atomic::<bool> = Atomic::new(false);
+
+    let producer = std::thread::spawn(|| {
+        DATA.store(42, Ordering::Relaxed);
+        READY.publish(true);
+    });
+
+    while !READY.consume() {
+        std::hint::spin_loop();
+    }
+    assert_eq!(DATA.load(Ordering::Relaxed), 42);
+    producer.join().expect("producer to finish");
+}
+
+#[test]
+fn should_check_pair32_methods() {
+    static PAIR: Atomic::<Pair32> = Atomic::new(Pair32(0, 0));
+
+    assert_eq!(core::mem::size_of::<Pair32>(), core::mem::size_of::<u64>());
+    assert_eq!(PAIR.load_pair(Ordering::Relaxed), (0, 0));
+    PAIR.store_pair((1, u32::max_value()), Ordering::Relaxed);
+    assert_eq!(PAIR.load_pair(Ordering::Relaxed), (1, u32::max_value()));
+    assert_eq!(PAIR.load(Ordering::Relaxed), Pair32(1, u32::max_value()));
+
+    assert_eq!(PAIR.compare_exchange_pair((1, 0), (2, 1), Ordering::Acquire, Ordering::Relaxed), Err((1, u32::max_value())));
+    assert_eq!(PAIR.compare_exchange_pair((1, u32::max_value()), (2, 1), Ordering::Acquire, Ordering::Relaxed), Ok((1, u32::max_value())));
+    assert_eq!(PAIR.load_pair(Ordering::Relaxed), (2, 1));
+}
+
+fn bool_byte(flag: &Atomic<bool>) -> u8 {
+    //Read raw byte to verify that no invalid bool can be observed
+    unsafe {
+        *(flag as *const Atomic<bool> as *const u8)
+    }
+}
+
+#[test]
+fn should_keep_atomic_bool_valid() {
+    let flag = Atomic::<bool>::new(false);
+    assert_eq!(bool_byte(&flag), 0);
+
+    flag.store(true, Ordering::Relaxed);
+    assert_eq!(bool_byte(&flag), 1);
+    assert!(flag.load(Ordering::Relaxed));
+
+    assert!(flag.swap(false, Ordering::Relaxed));
+    assert_eq!(bool_byte(&flag), 0);
+    assert!(!flag.swap(true, Ordering::Relaxed));
+    assert_eq!(bool_byte(&flag), 1);
+
+    assert_eq!(flag.compare_exchange(false, false, Ordering::Relaxed, Ordering::Relaxed), Err(true));
+    assert_eq!(bool_byte(&flag), 1);
+    assert_eq!(flag.compare_exchange(true, false, Ordering::Relaxed, Ordering::Relaxed), Ok(true));
+    assert_eq!(bool_byte(&flag), 0);
+    assert_eq!(flag.compare_exchange_weak(true, true, Ordering::Relaxed, Ordering::Relaxed), Err(false));
+    assert_eq!(bool_byte(&flag), 0);
+
+    for &val in &[false, true] {
+        for &arg in &[false, true] {
+            flag.store(val, Ordering::Relaxed);
+            assert_eq!(flag.fetch_and(arg, Ordering::Relaxed), val);
+            assert_eq!(bool_byte(&flag), (val & arg) as u8);
+
+            flag.store(val, Ordering::Relaxed);
+            assert_eq!(flag.fetch_or(arg, Ordering::Relaxed), val);
+            assert_eq!(bool_byte(&flag), (val | arg) as u8);
+
+            flag.store(val, Ordering::Relaxed);
+            assert_eq!(flag.fetch_xor(arg, Ordering::Relaxed), val);
+            assert_eq!(bool_byte(&flag), (val ^ arg) as u8);
+
+            flag.store(val, Ordering::Relaxed);
+            assert_eq!(flag.fetch_nand(arg, Ordering::Relaxed), val);
+            assert_eq!(bool_byte(&flag), !(val & arg) as u8);
+        }
+    }
+
+    let flag = Atomic::<bool>::new(true);
+    assert!(flag.into_inner());
+}
+
+#[test]
+fn should_respect_sign_in_fetch_min_max() {
+    let num = Atomic::<i32>::new(3);
+    assert_eq!(num.fetch_min(-5, Ordering::Relaxed), 3);
+    assert_eq!(num.load(Ordering::Relaxed), -5);
+    assert_eq!(num.fetch_max(-6, Ordering::Relaxed), -5);
+    assert_eq!(num.load(Ordering::Relaxed), -5);
+}
+
+#[test]
+fn should_read_and_write_seqlock() {
+    let mut lock = SeqLock::new([1u64, 2, 3]);
+    assert_eq!(lock.read(), [1, 2, 3]);
+    lock.write([4, 5, 6]);
+    assert_eq!(lock.read(), [4, 5, 6]);
+    lock.get_mut()[2] = 7;
+    assert_eq!(lock.into_inner(), [4, 5, 7]);
+
+    //Size is not multiple of u32, so value is copied by bytes
+    let lock = SeqLock::new([1u8, 2, 3]);
+    lock.write([4, 5, 6]);
+    assert_eq!(lock.read(), [4, 5, 6]);
+}
+
+#[test]
+fn should_never_read_torn_value_from_seqlock() {
+    static LOCK: SeqLock<[u64; 4]> = SeqLock::new([0; 4]);
+    const WRITES: u64 = if cfg!(miri) { 100 } else { 10_000 };
+
+    let writers: Vec<_> = (0..2).map(|_| std::thread::spawn(|| {
+        for idx in 1..=WRITES {
+            LOCK.write([idx; 4]);
+        }
+    })).collect();
+    let readers: Vec<_> = (0..2).map(|_| std::thread::spawn(|| {
+        loop {
+            let value = LOCK.read();
+            assert!(value.iter().all(|elem| *elem == value[0]), "torn read: {:?}", value);
+            if value[0] == WRITES {
+                break;
+            }
+        }
+    })).collect();
+
+    for worker in writers.into_iter().chain(readers) {
+        worker.join().expect("worker to finish");
+    }
+    assert_eq!(LOCK.read(), [WRITES; 4]);
+}
+
+#[test]
+fn should_wrap_on_fetch_add_wrapping() {
+    let num = Atomic::<u8>::new(u8::max_value() - 1);
+    assert_eq!(num.fetch_add_wrapping(1, Ordering::Relaxed), u8::max_value() - 1);
+    assert_eq!(num.fetch_add_wrapping(2, Ordering::Relaxed), u8::max_value());
+    assert_eq!(num.load(Ordering::Relaxed), 1);
+
+    let num = Atomic::<i32>::new(i32::max_value());
+    assert_eq!(num.fetch_add_wrapping(1, Ordering::Relaxed), i32::max_value());
+    assert_eq!(num.load(Ordering::Relaxed), i32::min_value());
+}
+
+#[test]
+fn should_return_new_maximum_on_update_max() {
+    let num = Atomic::<u32>::new(10);
+    assert_eq!(num.update_max(5, Ordering::Relaxed), 10);
+    assert_eq!(num.load(Ordering::Relaxed), 10);
+    assert_eq!(num.update_max(15, Ordering::Relaxed), 15);
+    assert_eq!(num.load(Ordering::Relaxed), 15);
+    assert_eq!(num.update_max(15, Ordering::Relaxed), 15);
+
+    let num = Atomic::<i16>::new(-10);
+    assert_eq!(num.update_max(-20, Ordering::Relaxed), -10);
+    assert_eq!(num.update_max(0, Ordering::Relaxed), 0);
+    assert_eq!(num.load(Ordering::Relaxed), 0);
+}
+
+#[test]
+fn should_xor_fold_atomics() {
+    let data = [Atomic::new(0b0001u32), Atomic::new(0b0011), Atomic::new(0b0110), Atomic::new(0b1000)];
+    assert_eq!(xor_fold(&data, Ordering::Relaxed), 0b1100);
+    assert_eq!(xor_fold(&data[..0], Ordering::Relaxed), 0);
+
+    data[0].store(0b0000, Ordering::Relaxed);
+    assert_eq!(xor_fold(&data, Ordering::Acquire), 0b1101);
+}
+
+#[test]
+fn should_exchange_with_acq_rel() {
+    let num = Atomic::<u16>::new(1);
+    assert_eq!(num.swap(2, Ordering::AcqRel), 1);
+    assert_eq!(num.exchange(3), 2);
+    assert_eq!(num.exchange(4), 3);
+    assert_eq!(num.load(Ordering::Acquire), 4);
+}
+
+#[test]
+fn should_hand_over_data_with_exchange() {
+    static DATA: Atomic::<u64> = Atomic::new(0);
+    static TOKEN: Atomic::<u8> = Atomic::new(0);
+
+    let producer = std::thread::spawn(|| {
+        DATA.store(42, Ordering::Relaxed);
+        TOKEN.exchange(1);
+    });
+
+    while TOKEN.exchange(0) != 1 {
+        std::hint::spin_loop();
+    }
+    assert_eq!(DATA.load(Ordering::Relaxed), 42);
+    producer.join().expect("producer to finish");
+}
+
+#[test]
+fn should_snapshot_and_write_all_array() {
+    let arr = [Atomic::new(1u16), Atomic::new(2), Atomic::new(3), Atomic::new(4)];
+    assert_eq!(Atomic::snapshot(&arr, Ordering::Relaxed), [1, 2, 3, 4]);
+
+    Atomic::write_all(&arr, &[5, 6, 7, 8], Ordering::Relaxed);
+    assert_eq!(Atomic::snapshot(&arr, Ordering::Acquire), [5, 6, 7, 8]);
+    assert_eq!(arr[2].load(Ordering::Relaxed), 7);
+}
+
+#[test]
+fn should_keep_value_on_barrier_cas() {
+    let num = Atomic::<i64>::new(-42);
+    assert_eq!(num.barrier_cas(Ordering::AcqRel), -42);
+    assert_eq!(num.barrier_cas(Ordering::Release), -42);
+    assert_eq!(num.barrier_cas(Ordering::SeqCst), -42);
+    assert_eq!(num.barrier_cas(Ordering::Relaxed), -42);
+    assert_eq!(num.load(Ordering::Relaxed), -42);
+
+    let value = Atomic::new(f32::NAN);
+    assert!(value.barrier_cas(Ordering::AcqRel).is_nan());
+}
+
+#[test]
+fn should_preserve_tag_on_fetch_add_masked() {
+    const TAG_MASK: usize = 0b111;
+    const ONE: usize = 1 << 3;
+
+    let word = Atomic::<usize>::new(0b101);
+    assert_eq!(word.fetch_add_masked(ONE, TAG_MASK, Ordering::AcqRel), 0b101);
+    assert_eq!(word.fetch_add_masked(ONE, TAG_MASK, Ordering::Relaxed), ONE | 0b101);
+    assert_eq!(word.load(Ordering::Relaxed), (2 * ONE) | 0b101);
+
+    word.store(!TAG_MASK | 0b010, Ordering::Relaxed);
+    assert_eq!(word.fetch_add_masked(ONE, TAG_MASK, Ordering::SeqCst), !TAG_MASK | 0b010);
+    assert_eq!(word.load(Ordering::Relaxed), 0b010);
+}
+
+fn new_word<T: Copy>(value: T) -> Atomic<T> {
+    Atomic::new_with_width::<4>(value)
+}
+
+#[test]
+fn should_create_atomic_with_expected_width() {
+    assert_eq!(new_word(5u32).load(Ordering::Relaxed), 5);
+    assert_eq!(new_word(-5i32).load(Ordering::Relaxed), -5);
+    assert_eq!(new_word(1.5f32).load(Ordering::Relaxed), 1.5);
+
+    static NUM: Atomic<u16> = Atomic::new_with_width::<2>(1);
+    assert_eq!(NUM.load(Ordering::Relaxed), 1);
+}
+
+#[test]
+fn should_pass_final_value_to_consume_with() {
+    let num = Atomic::<u32>::new(1);
+    num.fetch_add(2, Ordering::Relaxed);
+    assert_eq!(num.consume_with(|value| {
+        assert_eq!(value, 3);
+        value * 2
+    }), 6);
+}
+
+#[test]
+fn should_handle_null_in_pointer_atomic() {
+    let mut first = 1u32;
+    let mut second = 2u32;
+    let slot = Atomic::<*mut u32>::new(core::ptr::null_mut());
+
+    assert!(slot.is_null(Ordering::Relaxed));
+    assert_eq!(slot.take_if_non_null(Ordering::Acquire), None);
+
+    assert!(slot.set_if_null(&mut first, Ordering::Release));
+    assert!(!slot.is_null(Ordering::Acquire));
+    assert!(!slot.set_if_null(&mut second, Ordering::AcqRel));
+    assert_eq!(slot.load(Ordering::Relaxed), &mut first as *mut u32);
+
+    assert_eq!(slot.take_if_non_null(Ordering::AcqRel), Some(&mut first as *mut u32));
+    assert!(slot.is_null(Ordering::Relaxed));
+    assert_eq!(slot.take_if_non_null(Ordering::AcqRel), None);
+
+    assert!(slot.set_if_null(&mut second, Ordering::SeqCst));
+    assert_eq!(slot.take_if_non_null(Ordering::SeqCst), Some(&mut second as *mut u32));
+}
+
+#[test]
+fn should_commit_or_propagate_error_in_try_fetch_update() {
+    let num = Atomic::<u32>::new(5);
+
+    let result: Result<u32, &'static str> = num.try_fetch_update(Ordering::SeqCst, Ordering::SeqCst, |x| Ok(x * 2));
+    assert_eq!(result, Ok(5));
+    assert_eq!(num.load(Ordering::Relaxed), 10);
+
+    let result = num.try_fetch_update(Ordering::SeqCst, Ordering::SeqCst, |x| if x > 5 {
+        Err("too big")
+    } else {
+        Ok(x)
+    });
+    assert_eq!(result, Err("too big"));
+    assert_eq!(num.load(Ordering::Relaxed), 10);
+}
+
+#[test]
+fn should_record_into_histogram_buckets() {
+    let buckets = [Atomic::new(0u32), Atomic::new(0), Atomic::new(u32::max_value() - 1)];
+
+    assert_eq!(record(&buckets, 0, Ordering::Relaxed), Some(0));
+    assert_eq!(record(&buckets, 0, Ordering::Relaxed), Some(1));
+    assert_eq!(record(&buckets, 1, Ordering::AcqRel), Some(0));
+    assert_eq!(record(&buckets, 3, Ordering::Relaxed), None);
+
+    assert_eq!(record(&buckets, 2, Ordering::Relaxed), Some(u32::max_value() - 1));
+    assert_eq!(record(&buckets, 2, Ordering::Relaxed), Some(u32::max_value()));
+    assert_eq!(record(&buckets, 2, Ordering::Relaxed), Some(u32::max_value()));
+
+    assert_eq!(Atomic::snapshot(&buckets, Ordering::Relaxed), [2, 1, u32::max_value()]);
+}
+
+#[test]
+fn should_take_value_from_mailbox_with_swap_take() {
+    let mailbox = Atomic::<usize>::new(0);
+    assert_eq!(mailbox.swap_take(0, Ordering::Acquire), None);
+
+    mailbox.store(5, Ordering::Release);
+    assert_eq!(mailbox.swap_take(0, Ordering::Acquire), Some(5));
+    assert_eq!(mailbox.swap_take(0, Ordering::Acquire), None);
+    assert_eq!(mailbox.load(Ordering::Relaxed), 0);
+
+    let mailbox = Atomic::<i8>::new(3);
+    assert_eq!(mailbox.swap_take(-1, Ordering::AcqRel), Some(3));
+    assert_eq!(mailbox.swap_take(-1, Ordering::AcqRel), None);
+
+    let mut value = 1u8;
+    let mailbox = Atomic::<*mut u8>::new(&mut value);
+    assert_eq!(mailbox.swap_take(core::ptr::null_mut(), Ordering::AcqRel), Some(&mut value as *mut u8));
+    assert_eq!(mailbox.swap_take(core::ptr::null_mut(), Ordering::AcqRel), None);
+}
+
+#[test]
+fn should_update_with_jitter_on_forced_contention() {
+    static NUM: Atomic::<u32> = Atomic::new(0);
+    let mut calls = 0;
+
+    let result = NUM.fetch_update_jitter(Ordering::SeqCst, Ordering::SeqCst, |prev| {
+        calls += 1;
+        if calls < 4 {
+            NUM.fetch_add(1, Ordering::SeqCst);
+        }
+        Some(prev + 10)
+    });
+    assert_eq!(result, Ok(3));
+    //Weak CAS may fail spuriously, adding extra calls
+    assert!(calls >= 4);
+    assert_eq!(NUM.load(Ordering::SeqCst), 13);
+    assert_eq!(NUM.fetch_update_jitter(Ordering::SeqCst, Ordering::SeqCst, |_| None), Err(13));
+}
+
+#[test]
+fn should_update_with_jitter_across_threads() {
+    static NUM: Atomic::<u64> = Atomic::new(0);
+    const THREADS: u64 = 4;
+    const INCREMENTS: u64 = 1000;
+
+    let workers: Vec<_> = (0..THREADS).map(|_| std::thread::spawn(|| {
+        for _ in 0..INCREMENTS {
+            NUM.fetch_update_jitter(Ordering::AcqRel, Ordering::Acquire, |x| Some(x + 1)).expect("to update");
+        }
+    })).collect();
+
+    for worker in workers {
+        worker.join().expect("worker to finish");
+    }
+    assert_eq!(NUM.load(Ordering::Relaxed), THREADS * INCREMENTS);
+}
+
+#[cfg(not(feature = "strict-test"))]
+#[test]
+#[should_panic]
+fn should_keep_requested_ordering() {
+    //Invalid for load, so it panics unless remapped by strict-test
+    Atomic::<u32>::new(1).load(Ordering::Release);
+}
+
+#[test]
+fn should_round_trip_ipv4_addresses() {
+    use atomik::AtomicIpv4;
+    use std::net::Ipv4Addr;
+
+    let gateway = AtomicIpv4::default();
+    assert_eq!(gateway.load_addr(Ordering::Acquire), Ipv4Addr::UNSPECIFIED);
+
+    for addr in [Ipv4Addr::new(0, 0, 0, 0), Ipv4Addr::new(255, 255, 255, 255), Ipv4Addr::new(192, 168, 1, 1), Ipv4Addr::new(10, 0, 0, 254)] {
+        gateway.store_addr(addr, Ordering::Release);
+        assert_eq!(gateway.load_addr(Ordering::Acquire), addr);
+    }
+
+    let prev = gateway.swap_addr(Ipv4Addr::LOCALHOST, Ordering::AcqRel);
+    assert_eq!(prev, Ipv4Addr::new(10, 0, 0, 254));
+    assert_eq!(gateway.compare_exchange_addr(prev, Ipv4Addr::BROADCAST, Ordering::AcqRel, Ordering::Acquire), Err(Ipv4Addr::LOCALHOST));
+    assert_eq!(gateway.compare_exchange_addr(Ipv4Addr::LOCALHOST, Ipv4Addr::BROADCAST, Ordering::AcqRel, Ordering::Acquire), Ok(Ipv4Addr::LOCALHOST));
+    assert_eq!(format!("{:?}", gateway), "255.255.255.255");
+    assert_eq!(gateway.into_inner(), Ipv4Addr::BROADCAST);
+}
+
+#[test]
+fn should_call_retry_hook_on_forced_contention() {
+    static NUM: Atomic::<u32> = Atomic::new(0);
+    let mut calls = 0;
+    let mut retries = 0;
+
+    let result = NUM.fetch_update_yield(Ordering::SeqCst, Ordering::SeqCst, |prev| {
+        calls += 1;
+        if calls < 3 {
+            NUM.fetch_add(1, Ordering::SeqCst);
+        }
+        Some(prev * 2)
+    }, || retries += 1);
+    assert_eq!(result, Ok(2));
+    //Weak CAS may fail spuriously, adding extra retries, but each retry is followed by another call
+    assert!(retries >= 2);
+    assert_eq!(retries, calls - 1);
+    assert_eq!(NUM.load(Ordering::SeqCst), 4);
+
+    let retries_before = retries;
+    let result = NUM.fetch_update_yield(Ordering::SeqCst, Ordering::SeqCst, |_| None, || retries += 1);
+    assert_eq!(result, Err(4));
+    assert_eq!(retries, retries_before);
+}
+
+#[cfg(not(feature = "critical-section-polyfill"))]
+#[test]
+fn should_mutate_via_underlying_atomic() {
+    use core::sync::atomic::{AtomicBool, AtomicU32};
+
+    fn bump(num: &mut AtomicU32) {
+        *num.get_mut() += 5;
+        num.fetch_add(1, Ordering::Relaxed);
+    }
+
+    let mut num = Atomic::<u32>::new(1);
+    bump(num.get_mut_atomic());
+    assert_eq!(num.load(Ordering::Relaxed), 7);
+
+    let mut flag = Atomic::<bool>::new(false);
+    let flag_ref: &mut AtomicBool = flag.get_mut_atomic();
+    *flag_ref.get_mut() = true;
+    assert!(flag.load(Ordering::Relaxed));
+}
+
+#[test]
+fn should_return_stored_value_after_compare_exchange() {
+    let num = Atomic::<i16>::new(-5);
+    assert_eq!(num.compare_exchange_value(-5, 10, Ordering::AcqRel, Ordering::Acquire), (true, 10));
+    assert_eq!(num.compare_exchange_value(-5, 20, Ordering::AcqRel, Ordering::Acquire), (false, 10));
+    assert_eq!(num.load(Ordering::Relaxed), 10);
+}
+
+#[test]
+fn should_construct_atomic_for_type_with_compatible_layout() {
+    #[derive(Copy, Clone, Debug, PartialEq)]
+    #[repr(C, align(4))]
+    struct Rgba([u8; 4]);
+
+    let color = Atomic::new(Rgba([1, 2, 3, 4]));
+    assert_eq!(color.swap(Rgba([4, 3, 2, 1]), Ordering::AcqRel), Rgba([1, 2, 3, 4]));
+    assert_eq!(color.load(Ordering::Acquire), Rgba([4, 3, 2, 1]));
+}
+
+#[test]
+fn should_access_byte_lanes() {
+    const VALUE: u32 = 0x11223344;
+    let num = Atomic::<u32>::new(VALUE);
+
+    let lanes = num.as_byte_atomics();
+    for (lane, byte) in lanes.iter().zip(VALUE.to_ne_bytes()) {
+        assert_eq!(lane.load(Ordering::Relaxed), byte);
+    }
+
+    lanes[0].store(0xff, Ordering::Relaxed);
+    let mut expected = VALUE.to_ne_bytes();
+    expected[0] = 0xff;
+    assert_eq!(num.load(Ordering::Relaxed), u32::from_ne_bytes(expected));
+}
+
+#[test]
+fn should_update_using_seed() {
+    let num = Atomic::<u32>::new(10);
+    let mut seen = Vec::new();
+
+    let result = num.fetch_update_seeded(10, Ordering::AcqRel, Ordering::Acquire, |x| {
+        seen.push(x);
+        Some(x + 1)
+    });
+    assert_eq!(result, Ok(10));
+    //Spurious failure can only result in retry with the same value
+    assert!(seen.iter().all(|x| *x == 10));
+
+    seen.clear();
+    let result = num.fetch_update_seeded(3, Ordering::AcqRel, Ordering::Acquire, |x| {
+        seen.push(x);
+        Some(x + 1)
+    });
+    assert_eq!(result, Ok(11));
+    assert_eq!(seen[0], 3);
+    assert!(seen[1..].iter().all(|x| *x == 11));
+    assert_eq!(num.load(Ordering::Relaxed), 12);
+
+    assert_eq!(num.fetch_update_seeded(12, Ordering::AcqRel, Ordering::Acquire, |_| None), Err(12));
+}
+
+#[test]
+fn should_set_clear_and_toggle_flags() {
+    use atomik::Flags64;
+
+    let flags = Flags64::default();
+    assert!(!flags.set(0, Ordering::AcqRel));
+    assert!(flags.set(0, Ordering::AcqRel));
+    assert!(!flags.set(63, Ordering::AcqRel));
+    assert_eq!(flags.bits(Ordering::Acquire), (1 << 63) | 1);
+
+    assert!(flags.get(0, Ordering::Acquire));
+    assert!(!flags.get(1, Ordering::Acquire));
+    assert!(flags.get(63, Ordering::Acquire));
+
+    assert!(flags.clear(0, Ordering::AcqRel));
+    assert!(!flags.clear(0, Ordering::AcqRel));
+    assert!(!flags.get(0, Ordering::Acquire));
+
+    assert!(!flags.toggle(32, Ordering::AcqRel));
+    assert!(flags.get(32, Ordering::Acquire));
+    assert!(flags.toggle(32, Ordering::AcqRel));
+    assert!(!flags.get(32, Ordering::Acquire));
+
+    assert_eq!(flags.into_bits(), 1 << 63);
+}
+
+#[test]
+#[should_panic(expected = "index out of bounds")]
+fn should_panic_on_flag_out_of_bounds() {
+    let flags = atomik::Flags64::new(u64::MAX);
+    flags.get(64, Ordering::Relaxed);
+}
+
+#[cfg(not(feature = "critical-section-polyfill"))]
+#[test]
+fn should_convert_into_std_atomic() {
+    use core::sync::atomic::{AtomicI64, AtomicU32, AtomicBool};
+
+    let num = Atomic::<u32>::new(1);
+    num.fetch_add(41, Ordering::Relaxed);
+    let num: AtomicU32 = num.into_std();
+    assert_eq!(num.load(Ordering::Relaxed), 42);
+
+    let num: AtomicI64 = Atomic::<i64>::new(-1).into_std();
+    assert_eq!(num.into_inner(), -1);
+
+    let flag: AtomicBool = Atomic::new(true).into_std();
+    assert!(flag.into_inner());
+}
+
+#[test]
+fn should_report_change_on_relaxed_poll() {
+    static DATA: Atomic::<u64> = Atomic::new(0);
+    static VERSION: Atomic::<u32> = Atomic::new(0);
+
+    assert_eq!(VERSION.load_relaxed_then_acquire_if_changed(0), (0, false));
+
+    std::thread::spawn(|| {
+        DATA.store(42, Ordering::Relaxed);
+        VERSION.store(1, Ordering::Release);
+    }).join().expect("writer to finish");
+
+    assert_eq!(VERSION.load_relaxed_then_acquire_if_changed(0), (1, true));
+    assert_eq!(DATA.load(Ordering::Relaxed), 42);
+    assert_eq!(VERSION.load_relaxed_then_acquire_if_changed(1), (1, false));
+}
+
+#[test]
+fn should_detect_double_consume() {
+    let slot = Atomic::<u32>::new(7);
+    assert_eq!(slot.swap_once(0, Ordering::AcqRel), Ok(7));
+    assert_eq!(slot.swap_once(0, Ordering::AcqRel), Err(()));
+
+    let mut payload = 1u8;
+    let slot = Atomic::new(&mut payload as *mut u8);
+    assert!(slot.swap_once(core::ptr::null_mut(), Ordering::AcqRel).is_ok());
+    assert_eq!(slot.swap_once(core::ptr::null_mut(), Ordering::AcqRel), Err(()));
+}
+
+#[test]
+fn should_drain_accumulated_counter() {
+    let counter = Atomic::<u64>::new(0);
+    for _ in 0..3 {
+        counter.fetch_add(5, Ordering::Relaxed);
+    }
+    assert_eq!(counter.drain(Ordering::AcqRel), 15);
+    assert_eq!(counter.load(Ordering::Relaxed), 0);
+
+    counter.fetch_add(2, Ordering::Relaxed);
+    assert_eq!(counter.drain(Ordering::AcqRel), 2);
+    assert_eq!(counter.drain(Ordering::AcqRel), 0);
+}
+
+#[test]
+fn should_measure_contention() {
+    use atomik::ContentionMeter;
+
+    static NUM: Atomic::<u32> = Atomic::new(0);
+    let meter = ContentionMeter::new();
+    assert_eq!(meter.ratio(), 1.0);
+
+    meter.record_attempt();
+    meter.record_attempt();
+    meter.record_success();
+    assert_eq!(meter.counts(), (2, 1));
+    assert_eq!(meter.ratio(), 0.5);
+
+    meter.reset();
+    let mut calls = 0;
+    let result = NUM.fetch_update_metered(&meter, Ordering::SeqCst, Ordering::SeqCst, |prev| {
+        calls += 1;
+        if calls < 4 {
+            NUM.fetch_add(1, Ordering::SeqCst);
+        }
+        Some(prev + 1)
+    });
+    assert_eq!(result, Ok(3));
+    //Weak CAS may fail spuriously, adding extra attempts, but each attempt is preceded by a call
+    let (attempts, successes) = meter.counts();
+    assert!(attempts >= 4);
+    assert_eq!(attempts, calls);
+    assert_eq!(successes, 1);
+    assert_eq!(meter.ratio(), 1.0 / attempts as f32);
+
+    assert_eq!(NUM.fetch_update_metered(&meter, Ordering::SeqCst, Ordering::SeqCst, |_| None), Err(4));
+    assert_eq!(meter.counts(), (attempts, 1));
+}
+
+#[test]
+fn should_swap_non_null_pointers() {
+    use core::ptr::NonNull;
+
+    let mut first = 1u32;
+    let mut second = 2u32;
+    let first = NonNull::from(&mut first);
+    let second = NonNull::from(&mut second);
+
+    let head = Atomic::new(first);
+    assert_eq!(head.load_non_null(Ordering::Acquire), first);
+    assert_eq!(head.swap_non_null(second, Ordering::AcqRel), first);
+    assert_eq!(head.compare_exchange_non_null(first, second, Ordering::AcqRel, Ordering::Acquire), Err(second));
+    assert_eq!(head.compare_exchange_non_null(second, first, Ordering::AcqRel, Ordering::Acquire), Ok(second));
+    head.store_non_null(second, Ordering::Release);
+    assert_eq!(unsafe { *head.load_non_null(Ordering::Acquire).as_ref() }, 2);
+}
+
+#[test]
+fn should_sum_sharded_counter_across_threads() {
+    use atomik::ShardedCounter;
+
+    static COUNTER: ShardedCounter<8> = ShardedCounter::new();
+    const THREADS: u64 = 8;
+    const INCREMENTS: u64 = 1000;
+
+    let workers: Vec<_> = (1..=THREADS).map(|val| std::thread::spawn(move || {
+        for _ in 0..INCREMENTS {
+            COUNTER.add(val, Ordering::Relaxed);
+        }
+    })).collect();
+
+    for worker in workers {
+        worker.join().expect("worker to finish");
+    }
+    assert_eq!(COUNTER.sum(Ordering::Relaxed), INCREMENTS * THREADS * (THREADS + 1) / 2);
+}
+
+#[test]
+fn should_inspect_loaded_value() {
+    let num = Atomic::<u16>::new(9);
+    let mut seen = None;
+    num.inspect(Ordering::Acquire, |value| seen = Some(value));
+    assert_eq!(seen, Some(9));
+    assert_eq!(num.load(Ordering::Relaxed), 9);
+}
+
+#[test]
+fn should_handshake_via_named_acquire_release() {
+    static DATA: Atomic::<u32> = Atomic::new(0);
+    static READY: Atomic::<bool> = Atomic::new(false);
+
+    assert!(!READY.load_acquire());
+    let producer = std::thread::spawn(|| {
+        DATA.store(7, Ordering::Relaxed);
+        READY.store_release(true);
+    });
+
+    while !READY.load_acquire() {
+        std::hint::spin_loop();
+    }
+    assert_eq!(DATA.load(Ordering::Relaxed), 7);
+    producer.join().expect("producer to finish");
+
+    READY.store_release(false);
+    assert_eq!(READY.load_acquire(), READY.load(Ordering::Acquire));
+}
+
+#[test]
+fn should_apply_transition_table() {
+    const IDLE: u8 = 0;
+    const CONNECTING: u8 = 1;
+    const CONNECTED: u8 = 2;
+
+    const CONNECT: u8 = 0;
+    const ESTABLISHED: u8 = 1;
+    const DISCONNECT: u8 = 2;
+
+    const TABLE: &[(u8, u8, u8)] = &[
+        (IDLE, CONNECT, CONNECTING),
+        (CONNECTING, ESTABLISHED, CONNECTED),
+        (CONNECTING, DISCONNECT, IDLE),
+        (CONNECTED, DISCONNECT, IDLE),
+    ];
+
+    let state = Atomic::<u8>::new(IDLE);
+    assert_eq!(state.transition_table(ESTABLISHED, TABLE, Ordering::AcqRel), Err(IDLE));
+    assert_eq!(state.transition_table(CONNECT, TABLE, Ordering::AcqRel), Ok(CONNECTING));
+    assert_eq!(state.transition_table(CONNECT, TABLE, Ordering::AcqRel), Err(CONNECTING));
+    assert_eq!(state.transition_table(ESTABLISHED, TABLE, Ordering::AcqRel), Ok(CONNECTED));
+    assert_eq!(state.transition_table(DISCONNECT, TABLE, Ordering::AcqRel), Ok(IDLE));
+    assert_eq!(state.load(Ordering::Relaxed), IDLE);
+}
+
+macro_rules! impl_test_float {
+    ($ty:ident) => {
+        let payload_nan = $ty::from_bits($ty::NAN.to_bits() | 1);
+        let values = [0.0, -0.0, $ty::INFINITY, $ty::NEG_INFINITY, $ty::NAN, payload_nan, 1.5, $ty::MIN_POSITIVE];
+
+        let num = Atomic::<$ty>::new(0.0);
+        for value in values {
+            num.store(value, Ordering::Release);
+            assert_eq!(num.load(Ordering::Acquire).to_bits(), value.to_bits());
+        }
+
+        let mut prev = num.load(Ordering::Relaxed);
+        for value in values {
+            assert_eq!(num.swap(value, Ordering::AcqRel).to_bits(), prev.to_bits());
+            prev = value;
+        }
+
+        for value in values {
+            let current = num.load(Ordering::Relaxed);
+            assert_eq!(num.compare_exchange(current, value, Ordering::AcqRel, Ordering::Acquire).map($ty::to_bits), Ok(current.to_bits()));
+            assert_eq!(num.load(Ordering::Relaxed).to_bits(), value.to_bits());
+        }
+
+        //Comparison is bitwise
+        num.store(-0.0, Ordering::Relaxed);
+        assert_eq!(num.compare_exchange(0.0, 1.0, Ordering::AcqRel, Ordering::Acquire).map_err($ty::to_bits), Err((-0.0 as $ty).to_bits()));
+        num.store(payload_nan, Ordering::Relaxed);
+        assert_eq!(num.compare_exchange($ty::NAN, 1.0, Ordering::AcqRel, Ordering::Acquire).map_err($ty::to_bits), Err(payload_nan.to_bits()));
+        assert_eq!(num.compare_exchange(payload_nan, 1.0, Ordering::AcqRel, Ordering::Acquire).map($ty::to_bits), Ok(payload_nan.to_bits()));
+        assert_eq!(num.load(Ordering::Relaxed), 1.0);
+    };
+}
+
+#[test]
+fn should_round_trip_f32_special_values() {
+    impl_test_float!(f32);
+}
+
+#[test]
+fn should_round_trip_f64_special_values() {
+    impl_test_float!(f64);
+}
+
+#[test]
+fn should_fill_atomic_array_via_mutable_view() {
+    let mut arr: [Atomic<f32>; 8] = Default::default();
+    let view = Atomic::as_mut_slice(&mut arr);
+    for (idx, elem) in view.iter_mut().enumerate() {
+        *elem = idx as f32 * 0.5;
+    }
+
+    for (idx, elem) in arr.iter().enumerate() {
+        assert_eq!(elem.load(Ordering::Acquire), idx as f32 * 0.5);
+    }
+}
+
+#[test]
+fn should_read_stable_value_under_writes() {
+    static NUM: Atomic::<u64> = Atomic::new(0);
+    static IS_DONE: Atomic::<bool> = Atomic::new(false);
+    const LIMIT: u64 = 10000;
+
+    let writer = std::thread::spawn(|| {
+        for idx in 1..=LIMIT {
+            NUM.store(idx, Ordering::Release);
+        }
+        IS_DONE.store(true, Ordering::Release);
+    });
+
+    let mut last = 0;
+    while !IS_DONE.load(Ordering::Acquire) {
+        let value = NUM.read_stable(Ordering::Acquire);
+        assert!(value >= last);
+        assert!(value <= LIMIT);
+        last = value;
+    }
+    writer.join().expect("writer to finish");
+    assert_eq!(NUM.read_stable(Ordering::Acquire), LIMIT);
+}
+
+#[test]
+fn should_compose_update_builder() {
+    let num = Atomic::<u32>::new(90);
+    assert_eq!(num.update_builder().add(5).min(100).apply(Ordering::AcqRel), 90);
+    assert_eq!(num.load(Ordering::Relaxed), 95);
+    assert_eq!(num.update_builder().add(5).min(100).apply(Ordering::AcqRel), 95);
+    assert_eq!(num.update_builder().add(5).min(100).apply(Ordering::AcqRel), 100);
+    assert_eq!(num.load(Ordering::Relaxed), 100);
+
+    let num = Atomic::<i64>::new(-20);
+    assert_eq!(num.update_builder().add(5).max(0).apply(Ordering::SeqCst), -20);
+    assert_eq!(num.load(Ordering::Relaxed), 0);
+    assert_eq!(num.update_builder().sub(3).max(-2).map(|value| value * 10).apply(Ordering::SeqCst), 0);
+    assert_eq!(num.load(Ordering::Relaxed), -20);
+    assert_eq!(num.update_builder().apply(Ordering::SeqCst), -20);
+}
+
+#[test]
+fn should_return_cas_outcome() {
+    use atomik::CasOutcome;
+
+    let num = Atomic::<u8>::new(1);
+    match num.compare_exchange_outcome(1, 2, Ordering::AcqRel, Ordering::Acquire) {
+        CasOutcome::Won(prev) => assert_eq!(prev, 1),
+        CasOutcome::Lost(actual) => panic!("Unexpectedly lost to {}", actual),
+    }
+
+    let outcome = num.compare_exchange_outcome(1, 3, Ordering::AcqRel, Ordering::Acquire);
+    match outcome {
+        CasOutcome::Won(prev) => panic!("Unexpectedly won over {}", prev),
+        CasOutcome::Lost(actual) => assert_eq!(actual, 2),
+    }
+    assert!(!outcome.is_won());
+    assert_eq!(outcome.into_result(), Err(2));
+    assert_eq!(num.load(Ordering::Relaxed), 2);
+}
+
+#[test]
+fn should_store_and_return_previous_value() {
+    let left = Atomic::<i32>::new(-1);
+    let right = Atomic::<i32>::new(-1);
+    for val in [0, 5, i32::MIN, i32::MAX] {
+        assert_eq!(left.store_return_prev(val, Ordering::AcqRel), right.swap(val, Ordering::AcqRel));
+    }
+    assert_eq!(left.load(Ordering::Relaxed), right.load(Ordering::Relaxed));
+}
+
+#[test]
+fn should_load_into_buffer() {
+    let pair = Atomic::new(Pair32(1, 2));
+    let mut out = Pair32::default();
+    pair.load_into(&mut out, Ordering::Acquire);
+    assert_eq!(out, pair.load(Ordering::Acquire));
+
+    let num = Atomic::<f64>::new(-2.5);
+    let mut out = 0.0;
+    num.load_into(&mut out, Ordering::Relaxed);
+    assert_eq!(out.to_bits(), num.load(Ordering::Relaxed).to_bits());
+}
+
+#[test]
+fn should_fetch_update_fast() {
+    static NUM: Atomic::<u32> = Atomic::new(1);
+
+    assert_eq!(NUM.fetch_update_fast(Ordering::SeqCst, Ordering::SeqCst, |_| None), Err(1));
+    assert_eq!(NUM.fetch_update_fast(Ordering::SeqCst, Ordering::SeqCst, |x| Some(x * 3)), Ok(1));
+    assert_eq!(NUM.load(Ordering::SeqCst), 3);
+
+    let mut calls = 0;
+    let result = NUM.fetch_update_fast(Ordering::SeqCst, Ordering::SeqCst, |x| {
+        calls += 1;
+        if calls < 3 {
+            NUM.fetch_add(1, Ordering::SeqCst);
+        }
+        Some(x * 3)
+    });
+    assert_eq!(result, Ok(5));
+    //Weak CAS may fail spuriously, adding extra calls
+    assert!(calls >= 3);
+    assert_eq!(NUM.load(Ordering::SeqCst), 15);
+
+    let mut calls = 0;
+    let result = NUM.fetch_update_fast(Ordering::SeqCst, Ordering::SeqCst, |x| {
+        calls += 1;
+        if calls == 1 {
+            NUM.fetch_add(1, Ordering::SeqCst);
+            Some(x)
+        } else {
+            None
+        }
+    });
+    assert_eq!(result, Err(16));
+}
+
+#[test]
+fn should_swap_niche_optimized_options() {
+    use core::num::{NonZeroU32, NonZeroI64};
+
+    assert_eq!(core::mem::size_of::<Option<NonZeroU32>>(), core::mem::size_of::<u32>());
+    let one = NonZeroU32::new(1).unwrap();
+    let two = NonZeroU32::new(2).unwrap();
+
+    let slot = Atomic::<Option<NonZeroU32>>::new(None);
+    assert_eq!(slot.take(Ordering::AcqRel), None);
+    assert_eq!(slot.get_or_insert(one, Ordering::AcqRel), one);
+    assert_eq!(slot.get_or_insert(two, Ordering::AcqRel), one);
+    assert_eq!(slot.replace(two, Ordering::AcqRel), Some(one));
+    assert_eq!(slot.load(Ordering::Acquire), Some(two));
+    assert_eq!(slot.take(Ordering::AcqRel), Some(two));
+    assert_eq!(slot.load(Ordering::Acquire), None);
+    assert_eq!(slot.compare_exchange(None, Some(one), Ordering::AcqRel, Ordering::Acquire), Ok(None));
+
+    let minus = NonZeroI64::new(-1).unwrap();
+    let slot = Atomic::new(Some(minus));
+    assert_eq!(slot.take(Ordering::AcqRel), Some(minus));
+    assert_eq!(slot.replace(minus, Ordering::AcqRel), None);
+}
+
+#[test]
+fn should_compare_exchange_any_of_accepted() {
+    const IDLE: u8 = 0;
+    const PAUSED: u8 = 1;
+    const RUNNING: u8 = 2;
+    const STOPPED: u8 = 3;
+
+    let state = Atomic::<u8>::new(PAUSED);
+    assert_eq!(state.compare_exchange_any(&[IDLE, PAUSED], RUNNING, Ordering::AcqRel, Ordering::Acquire), Ok(PAUSED));
+    assert_eq!(state.compare_exchange_any(&[IDLE, PAUSED], RUNNING, Ordering::AcqRel, Ordering::Acquire), Err(RUNNING));
+    assert_eq!(state.compare_exchange_any(&[RUNNING], STOPPED, Ordering::AcqRel, Ordering::Acquire), Ok(RUNNING));
+    assert_eq!(state.compare_exchange_any(&[], IDLE, Ordering::AcqRel, Ordering::Acquire), Err(STOPPED));
+    assert_eq!(state.load(Ordering::Relaxed), STOPPED);
+}
+
+#[test]
+fn should_swap_bytes_in_place() {
+    let num = Atomic::<u32>::new(0x12345678);
+    assert_eq!(num.fetch_swap_bytes(Ordering::AcqRel), 0x12345678);
+    assert_eq!(num.load(Ordering::Relaxed), 0x78563412);
+    assert_eq!(num.fetch_swap_bytes(Ordering::AcqRel), 0x78563412);
+    assert_eq!(num.load(Ordering::Relaxed), 0x12345678);
+
+    let num = Atomic::<i16>::new(0x0102);
+    assert_eq!(num.fetch_swap_bytes(Ordering::Relaxed), 0x0102);
+    assert_eq!(num.load(Ordering::Relaxed), 0x0201);
+
+    let num = Atomic::<u8>::new(0xab);
+    assert_eq!(num.fetch_swap_bytes(Ordering::Relaxed), 0xab);
+    assert_eq!(num.load(Ordering::Relaxed), 0xab);
+}
+
+#[test]
+fn should_access_atomic_via_single_thread_guard() {
+    let num = Atomic::<u16>::new(1);
+    let guard = num.single_thread_guard();
+    assert_eq!(guard.swap(2, Ordering::Relaxed), 1);
+    guard.store(3, Ordering::Relaxed);
+    assert_eq!(guard.load(Ordering::Relaxed), 3);
+    drop(guard);
+    assert_eq!(num.load(Ordering::Relaxed), 3);
+}
+
+#[test]
+fn should_update_seqlock_value() {
+    let lock = SeqLock::new([1u64, 2]);
+    assert_eq!(lock.update(|[first, second]| [first + 1, second * 2]), [1, 2]);
+    assert_eq!(lock.read(), [2, 4]);
+}
+
+#[test]
+fn should_accumulate_duration_with_nanos_carry() {
+    use atomik::AtomicDuration;
+    use core::time::Duration;
+
+    let elapsed = AtomicDuration::default();
+    assert_eq!(elapsed.fetch_add(Duration::new(1, 600_000_000), Ordering::AcqRel), Duration::ZERO);
+    assert_eq!(elapsed.fetch_add(Duration::new(2, 700_000_000), Ordering::AcqRel), Duration::new(1, 600_000_000));
+    assert_eq!(elapsed.load(Ordering::Acquire), Duration::new(4, 300_000_000));
+
+    elapsed.store(Duration::new(u64::MAX, 999_999_999), Ordering::Release);
+    elapsed.fetch_add(Duration::from_nanos(1), Ordering::AcqRel);
+    assert_eq!(elapsed.load(Ordering::Acquire), Duration::MAX);
+}
+
+#[test]
+fn should_load_pointer_then_read_through_it() {
+    let mut value = 42u32;
+    let ptr = Atomic::new(core::ptr::null_mut::<u32>());
+    ptr.store(&mut value, Ordering::Release);
+    assert_eq!(ptr.load_then(Ordering::Acquire, |ptr| unsafe { *ptr }), 42);
+
+    let value = 7u64;
+    let ptr = Atomic::new(&value as *const u64);
+    assert_eq!(ptr.load_then(Ordering::Acquire, |ptr| unsafe { ptr.as_ref() }.copied()), Some(7));
+}
+
+#[test]
+fn should_access_atomic_through_pin() {
+    let atomic = Atomic::new(1u32);
+    let pinned = core::pin::Pin::new(&atomic);
+    pinned.store_pinned(5, Ordering::Release);
+    assert_eq!(pinned.load_pinned(Ordering::Acquire), 5);
+    assert_eq!(atomic.load(Ordering::Relaxed), 5);
+}
+
+#[test]
+fn should_swap_with_id() {
+    let atomic = Atomic::new(1u32);
+    let mut calls = 0;
+    let result = atomic.swap_with_id(2, || {
+        calls += 1;
+        100
+    }, Ordering::AcqRel);
+    assert_eq!(result, (1, 100));
+    assert_eq!(calls, 1);
+    assert_eq!(atomic.load(Ordering::Acquire), 2);
+}
+
+#[test]
+fn should_wait_until_predicate_or_timeout() {
+    let flag = Atomic::new(0u8);
+    let mut ticks = 0;
+    //Flag is set by "interrupt" on the third tick
+    let result = flag.wait_until_timeout(|flag| flag == 1, || {
+        ticks += 1;
+        if ticks == 3 {
+            flag.store(1, Ordering::Release);
+        }
+        ticks
+    }, 10, Ordering::Acquire);
+    assert_eq!(result, Ok(1));
+    assert_eq!(ticks, 3);
+
+    let mut ticks = 0;
+    let result = flag.wait_until_timeout(|flag| flag == 2, || {
+        ticks += 1;
+        ticks
+    }, 10, Ordering::Acquire);
+    assert_eq!(result, Err(()));
+    assert_eq!(ticks, 10);
+}
+
+#[test]
+#[cfg_attr(miri, ignore)]
+fn should_never_read_torn_value_from_versioned() {
+    static VALUE: Versioned<u64> = Versioned::new(0);
+    const WRITES: u64 = 10_000;
+
+    let writer = std::thread::spawn(|| {
+        for idx in 1..=WRITES {
+            VALUE.write(idx << 32 | idx);
+        }
+    });
+    let readers: Vec<_> = (0..2).map(|_| std::thread::spawn(|| {
+        let mut last = 0;
+        loop {
+            let value = VALUE.read();
+            assert_eq!(value >> 32, value & u32::MAX as u64, "torn read: {:#x}", value);
+            assert!(value >= last);
+            last = value;
+            if value & u32::MAX as u64 == WRITES {
+                break;
+            }
+        }
+    })).collect();
+
+    for worker in core::iter::once(writer).chain(readers) {
+        worker.join().expect("worker to finish");
+    }
+    assert_eq!(VALUE.version(), WRITES as u32 * 2);
+    assert_eq!(VALUE.read(), WRITES << 32 | WRITES);
+}
+
+#[test]
+fn should_check_required_bits_on_fetch_or() {
+    let workers = Atomic::new(0u8);
+    let required = 0b111;
+    assert!(!workers.fetch_or_check(0b001, required, Ordering::AcqRel));
+    assert!(!workers.fetch_or_check(0b100, required, Ordering::AcqRel));
+    assert!(!workers.fetch_or_check(0b1000, required, Ordering::AcqRel));
+    assert!(workers.fetch_or_check(0b010, required, Ordering::AcqRel));
+    assert_eq!(workers.load(Ordering::Acquire), 0b1111);
+
+    let signed = Atomic::new(0i32);
+    assert!(signed.fetch_or_check(-1, -1, Ordering::AcqRel));
+}
+
+#[test]
+fn should_verify_stored_value() {
+    let atomic = Atomic::new(0u64);
+    atomic.store_verified(u64::MAX, Ordering::Release);
+    assert_eq!(atomic.load(Ordering::Acquire), u64::MAX);
+
+    let atomic = Atomic::new(0.0f32);
+    atomic.store_verified(f32::NAN, Ordering::Release);
+    assert!(atomic.load(Ordering::Acquire).is_nan());
+}
+
+#[test]
+fn should_compare_exchange_masked_field() {
+    const LOW: u16 = 0x00ff;
+    let word = Atomic::new(0x1203u16);
+
+    //High field changes concurrently, but doesn't affect low field
+    word.store(0x3403, Ordering::Release);
+    assert_eq!(word.compare_exchange_masked(LOW, 0x03, 0x07, Ordering::AcqRel, Ordering::Acquire), Ok(0x3403));
+    assert_eq!(word.load(Ordering::Acquire), 0x3407);
+
+    assert_eq!(word.compare_exchange_masked(LOW, 0x03, 0x09, Ordering::AcqRel, Ordering::Acquire), Err(0x3407));
+    assert_eq!(word.load(Ordering::Acquire), 0x3407);
+
+    //Bits of new value outside of mask are ignored
+    assert_eq!(word.compare_exchange_masked(!LOW, 0x3400, 0x56ff, Ordering::AcqRel, Ordering::Acquire), Ok(0x3407));
+    assert_eq!(word.load(Ordering::Acquire), 0x5607);
+}
+
+#[test]
+fn should_reset_all_elements() {
+    let mut arr: [Atomic<u32>; 16] = core::array::from_fn(|idx| Atomic::new(idx as u32));
+    Atomic::reset_all(&mut arr, 7);
+    assert!(arr.iter().all(|elem| elem.load(Ordering::Acquire) == 7));
+}
+
+#[test]
+fn should_publish_committed_value_to_mirror() {
+    static VALUE: Atomic<u32> = Atomic::new(1);
+    let mirror = Atomic::new(0u32);
+    let mut calls = 0;
+
+    let result = VALUE.fetch_update_publish(&mirror, Ordering::AcqRel, Ordering::Acquire, |prev| {
+        calls += 1;
+        //Force contention on the first attempt, so mirror must not get value of failed attempt
+        if calls == 1 {
+            VALUE.store(5, Ordering::Release);
+        }
+        Some(prev + 10)
+    });
+    assert_eq!(result, Ok(5));
+    assert_eq!(VALUE.load(Ordering::Acquire), 15);
+    assert_eq!(mirror.load(Ordering::Acquire), 15);
+
+    assert_eq!(VALUE.fetch_update_publish(&mirror, Ordering::AcqRel, Ordering::Acquire, |_| None), Err(15));
+    assert_eq!(mirror.load(Ordering::Acquire), 15);
+}
+
+macro_rules! impl_test_nand_boundaries {
+    ($($ty:ident),*) => {$(
+        #[test]
+        fn $ty() {
+            let patterns = [0, $ty::MAX, $ty::MIN, 1, !1, (0x5555_5555_5555_5555u64 as $ty), (0xaaaa_aaaa_aaaa_aaaau64 as $ty)];
+            let atomic = Atomic::<$ty>::new(0);
+            for &val in patterns.iter() {
+                for &arg in patterns.iter() {
+                    atomic.store(val, Ordering::Relaxed);
+                    assert_eq!(atomic.fetch_nand(arg, Ordering::AcqRel), val);
+                    assert_eq!(atomic.load(Ordering::Relaxed), !(val & arg), "nand({:#x}, {:#x})", val, arg);
+                }
+            }
+
+            //Nand with zero sets all bits, while nand with all bits set inverts value
+            atomic.store(0, Ordering::Relaxed);
+            atomic.fetch_nand(0, Ordering::AcqRel);
+            assert_eq!(atomic.load(Ordering::Relaxed), !0);
+            atomic.fetch_nand(!0, Ordering::AcqRel);
+            assert_eq!(atomic.load(Ordering::Relaxed), 0);
+        }
+    )*};
+}
+
+mod fetch_nand_boundaries {
+    use super::*;
+
+    impl_test_nand_boundaries!(u8, i8, u16, i16, u32, i32, u64, i64, usize, isize);
+    #[cfg(all(feature = "atomic-128", target_has_atomic = "128"))]
+    impl_test_nand_boundaries!(u128, i128);
+}
+
+#[test]
+fn should_create_lock_free_atomic() {
+    static COUNTER: Atomic<u32> = Atomic::new_lock_free(0);
+    COUNTER.fetch_add(1, Ordering::Relaxed);
+    assert_eq!(COUNTER.load(Ordering::Relaxed), 1);
+
+    assert!(Atomic::<u32>::is_always_lock_free());
+    assert!(Atomic::<bool>::is_always_lock_free());
+    assert!(!Atomic::<[u8; 3]>::is_always_lock_free());
+    assert!(!Atomic::<[u16; 2]>::is_always_lock_free());
+}
+
+#[test]
+fn should_wrap_index_on_fetch_wrapping_inc() {
+    let cursor = Atomic::new(0usize);
+    assert_eq!(cursor.fetch_wrapping_inc(3, Ordering::AcqRel), 0);
+    assert_eq!(cursor.fetch_wrapping_inc(3, Ordering::AcqRel), 1);
+    assert_eq!(cursor.fetch_wrapping_inc(3, Ordering::AcqRel), 2);
+    assert_eq!(cursor.load(Ordering::Acquire), 0);
+
+    let cursor = Atomic::new(usize::MAX - 1);
+    assert_eq!(cursor.fetch_wrapping_inc(usize::MAX, Ordering::AcqRel), usize::MAX - 1);
+    assert_eq!(cursor.load(Ordering::Acquire), 0);
+
+    assert_eq!(cursor.fetch_wrapping_inc(1, Ordering::AcqRel), 0);
+    assert_eq!(cursor.load(Ordering::Acquire), 0);
+}
+
+#[test]
+#[should_panic(expected = "modulus must not be zero")]
+fn should_panic_on_zero_modulus() {
+    Atomic::new(0usize).fetch_wrapping_inc(0, Ordering::AcqRel);
+}
+
+#[test]
+fn should_retry_compare_exchange_while_condition_holds() {
+    let lock = Atomic::new(true);
+    let shutdown = Atomic::new(false);
+    let attempts = Atomic::new(0u32);
+
+    //Shutdown is requested on the third attempt, while lock is still held
+    let result = lock.compare_exchange_while(false, true, || {
+        if attempts.fetch_add(1, Ordering::Relaxed) == 2 {
+            shutdown.store(true, Ordering::Release);
+        }
+        !shutdown.load(Ordering::Acquire)
+    }, Ordering::Acquire, Ordering::Relaxed);
+    assert_eq!(result, Err(true));
+    assert_eq!(attempts.load(Ordering::Relaxed), 3);
+
+    //Lock is released on the second attempt
+    attempts.store(0, Ordering::Relaxed);
+    let result = lock.compare_exchange_while(false, true, || {
+        if attempts.fetch_add(1, Ordering::Relaxed) == 1 {
+            lock.store(false, Ordering::Release);
+        }
+        true
+    }, Ordering::Acquire, Ordering::Relaxed);
+    assert_eq!(result, Ok(false));
+    assert!(lock.load(Ordering::Relaxed));
+}
+
+#[test]
+fn should_track_min_and_max_samples() {
+    use atomik::MinMax;
+
+    let latency = MinMax::<u32>::new();
+    assert_eq!(latency.min(Ordering::Relaxed), u32::MAX);
+    assert_eq!(latency.max(Ordering::Relaxed), u32::MIN);
+
+    for &sample in &[15, 7, 42, 9, 30] {
+        latency.record(sample, Ordering::Relaxed);
+    }
+    assert_eq!(latency.min(Ordering::Relaxed), 7);
+    assert_eq!(latency.max(Ordering::Relaxed), 42);
+
+    let offset = MinMax::<i16>::default();
+    for &sample in &[-3, 5, -10, 0] {
+        offset.record(sample, Ordering::Relaxed);
+    }
+    assert_eq!(offset.min(Ordering::Relaxed), -10);
+    assert_eq!(offset.max(Ordering::Relaxed), 5);
+}
+
+#[test]
+fn should_publish_once() {
+    let config = Atomic::new(0u32);
+    assert!(config.publish_once(5, Ordering::Release));
+    assert!(!config.publish_once(5, Ordering::Release));
+    assert!(!config.publish_once(7, Ordering::Release));
+    assert_eq!(config.load(Ordering::Acquire), 5);
+
+    let mut first = 1u8;
+    let mut second = 2u8;
+    let ptr = Atomic::new(core::ptr::null_mut::<u8>());
+    assert!(ptr.publish_once(&mut first, Ordering::Release));
+    assert!(!ptr.publish_once(&mut second, Ordering::Release));
+    assert_eq!(ptr.load_then(Ordering::Acquire, |ptr| unsafe { *ptr }), 1);
+}
+
+#[test]
+fn should_increment_and_decrement_by_one() {
+    let counter = Atomic::new(0u16);
+    assert_eq!(counter.increment(Ordering::Relaxed), 0);
+    assert_eq!(counter.increment(Ordering::Relaxed), 1);
+    assert_eq!(counter.decrement(Ordering::Relaxed), 2);
+    assert_eq!(counter.load(Ordering::Relaxed), 1);
+
+    let counter = Atomic::new(i8::MIN);
+    assert_eq!(counter.decrement(Ordering::Relaxed), i8::MIN);
+    assert_eq!(counter.increment(Ordering::Relaxed), i8::MAX);
+    assert_eq!(counter.load(Ordering::Relaxed), i8::MIN);
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[repr(transparent)]
+struct Handle(u32);
+
+atomik::impl_atomic_transparent!(Handle => u32);
+
+#[test]
+fn should_use_integer_ops_on_transparent_newtype() {
+    static HANDLE: Atomic<Handle> = Atomic::new(Handle(1));
+
+    assert_eq!(HANDLE.fetch_add(Handle(2), Ordering::AcqRel), Handle(1));
+    assert_eq!(HANDLE.compare_exchange(Handle(3), Handle(10), Ordering::AcqRel, Ordering::Acquire), Ok(Handle(3)));
+    assert_eq!(HANDLE.compare_exchange(Handle(3), Handle(20), Ordering::AcqRel, Ordering::Acquire), Err(Handle(10)));
+    assert_eq!(HANDLE.fetch_sub(Handle(4), Ordering::AcqRel), Handle(10));
+    assert_eq!(HANDLE.fetch_max(Handle(8), Ordering::AcqRel), Handle(6));
+    assert_eq!(HANDLE.fetch_or(Handle(1), Ordering::AcqRel), Handle(8));
+    assert_eq!(HANDLE.as_inner().load(Ordering::Acquire), 9);
+}
+
+macro_rules! impl_test_primitive_matches_core {
+    ($($name:ident: $ty:ident($atomic:ident) => [$($val:expr),*];)*) => {$(
+        #[test]
+        fn $name() {
+            use core::sync::atomic::$atomic;
+
+            let values = [$($val),*];
+            let generic = Atomic::<$ty>::new(values[0]);
+            let direct = $atomic::new(values[0]);
+            for &val in values.iter() {
+                assert_eq!(generic.load(Ordering::Acquire), direct.load(Ordering::Acquire));
+                assert_eq!(generic.swap(val, Ordering::AcqRel), direct.swap(val, Ordering::AcqRel));
+                for &current in values.iter() {
+                    assert_eq!(
+                        generic.compare_exchange(current, values[0], Ordering::AcqRel, Ordering::Acquire),
+                        direct.compare_exchange(current, values[0], Ordering::AcqRel, Ordering::Acquire)
+                    );
+                }
+                generic.store(val, Ordering::Release);
+                direct.store(val, Ordering::Release);
+            }
+            assert_eq!(generic.into_inner(), direct.into_inner());
+        }
+    )*};
+}
+
+mod primitive_matches_core {
+    use super::*;
+
+    impl_test_primitive_matches_core! {
+        bool_matches: bool(AtomicBool) => [false, true];
+        u8_matches: u8(AtomicU8) => [0, 1, u8::MAX, 0x5a];
+        i8_matches: i8(AtomicI8) => [0, -1, i8::MIN, i8::MAX];
+        u16_matches: u16(AtomicU16) => [0, 1, u16::MAX, 0x5aa5];
+        i32_matches: i32(AtomicI32) => [0, -1, i32::MIN, i32::MAX];
+        u64_matches: u64(AtomicU64) => [0, 1, u64::MAX, 0x5aa5_5aa5_5aa5_5aa5];
+    }
+}
+
+#[test]
+fn should_call_on_attempt_for_each_observed_value() {
+    static VALUE: Atomic<u32> = Atomic::new(0);
+    let mut calls = 0;
+    let mut attempts = Vec::new();
+
+    let result = VALUE.fetch_update_with_attempt(Ordering::AcqRel, Ordering::Acquire, |prev| {
+        calls += 1;
+        //Force contention on the first two attempts
+        if calls <= 2 {
+            VALUE.store(calls * 10, Ordering::Release);
+        }
+        Some(prev + 1)
+    }, |prev| attempts.push(prev));
+    assert_eq!(result, Ok(20));
+    assert_eq!(VALUE.load(Ordering::Acquire), 21);
+    //Weak CAS may fail spuriously, repeating attempt with the same value, so only deduplicated sequence is exact
+    assert!(attempts.len() >= 3);
+    assert_eq!(attempts.len(), calls as usize);
+    attempts.dedup();
+    assert_eq!(attempts, [0, 10, 20]);
+
+    attempts.clear();
+    assert_eq!(VALUE.fetch_update_with_attempt(Ordering::AcqRel, Ordering::Acquire, |_| None, |prev| attempts.push(prev)), Err(21));
+    assert!(attempts.is_empty());
+}
+
+#[test]
+fn should_report_changed_elements_since_snapshot() {
+    let counters: [Atomic<u32>; 5] = core::array::from_fn(|idx| Atomic::new(idx as u32));
+    let prev = Atomic::snapshot(&counters, Ordering::Acquire);
+
+    counters[1].store(10, Ordering::Release);
+    counters[4].store(40, Ordering::Release);
+    //Same value is not a change
+    counters[2].store(2, Ordering::Release);
+
+    let changes: Vec<_> = Atomic::diff_snapshot(&counters, &prev, Ordering::Acquire).collect();
+    assert_eq!(changes, [(1, 10), (4, 40)]);
+}
+
+#[test]
+fn should_pass_previous_value_to_cleanup() {
+    let slot = Atomic::new(1u32);
+    let mut cleaned = Vec::new();
+    slot.swap_cleanup(2, Ordering::AcqRel, |prev| cleaned.push(prev));
+    assert_eq!(cleaned, [1]);
+    slot.swap_cleanup(3, Ordering::AcqRel, |prev| cleaned.push(prev));
+    assert_eq!(cleaned, [1, 2]);
+    assert_eq!(slot.load(Ordering::Acquire), 3);
+}
+
+#[test]
+fn should_give_up_fetch_update_after_deadline() {
+    static VALUE: Atomic<u32> = Atomic::new(0);
+    let mut ticks = 0;
+
+    //Value is modified on every attempt, so only deadline stops retries
+    let result = VALUE.fetch_update_deadline(Ordering::AcqRel, Ordering::Acquire, |prev| {
+        VALUE.store(prev + 1, Ordering::Release);
+        Some(prev + 100)
+    }, || {
+        ticks += 1;
+        ticks
+    }, 3);
+    assert_eq!(result, Err(3));
+    //Clock is checked after each failed attempt, and retries stop once deadline is reached
+    assert!(ticks >= 3);
+    assert_eq!(VALUE.load(Ordering::Acquire), 3);
+
+    let result = VALUE.fetch_update_deadline(Ordering::AcqRel, Ordering::Acquire, |prev| Some(prev + 1), || u64::MAX, 0);
+    assert!(matches!(result, Ok(3) | Err(3)));
+}
+
+#[test]
+fn should_init_static_from_const_computation() {
+    const fn mask(bits: u32) -> u64 {
+        (1 << bits) - 1
+    }
+
+    static MASK: Atomic<u64> = atomik::atomic_const!(u64 => mask(12));
+    assert_eq!(MASK.load(Ordering::Relaxed), 0xfff);
+
+    let local = atomik::atomic_const!(u64 => mask(4));
+    assert_eq!(local.into_inner(), 0xf);
+}
+
+#[test]
+fn should_compare_exchange_by_projected_key() {
+    //Version in high half, payload in low half
+    let version = |word: u32| word >> 16;
+    let word = Atomic::new(0x0001_00aau32);
+
+    assert_eq!(word.compare_exchange_by(version, 1, 0x0002_00bb, Ordering::AcqRel, Ordering::Acquire), Ok(0x0001_00aa));
+    assert_eq!(word.compare_exchange_by(version, 1, 0x0002_00cc, Ordering::AcqRel, Ordering::Acquire), Err(0x0002_00bb));
+    assert_eq!(word.load(Ordering::Acquire), 0x0002_00bb);
+}
+
+#[test]
+fn should_increase_read_sequence_on_each_load() {
+    use atomik::SequencedAtomic;
+
+    let cache = SequencedAtomic::new(5u32);
+    assert_eq!(cache.load_seq(Ordering::Acquire), (5, 0));
+    cache.store(7, Ordering::Release);
+    assert_eq!(cache.load_seq(Ordering::Acquire), (7, 1));
+    assert_eq!(cache.load_seq(Ordering::Acquire), (7, 2));
+    //Plain access is not sequenced
+    assert_eq!(cache.as_atomic().load(Ordering::Acquire), 7);
+    assert_eq!(cache.sequence(), 3);
+    assert_eq!(cache.into_inner(), 7);
+}
+
+#[test]
+fn should_claim_first_clear_bit_until_full() {
+    let bitmap = Atomic::new(0u64);
+    let mut claimed: Vec<_> = (0..64).map(|_| bitmap.claim_first_clear(Ordering::AcqRel).expect("free bit")).collect();
+    assert_eq!(bitmap.claim_first_clear(Ordering::AcqRel), None);
+    assert_eq!(bitmap.load(Ordering::Acquire), u64::MAX);
+    claimed.dedup();
+    assert_eq!(claimed, (0..64).collect::<Vec<_>>());
+
+    //Freed slot is claimed again
+    let bitmap = Atomic::new(0b1011u32);
+    assert_eq!(bitmap.claim_first_clear(Ordering::AcqRel), Some(2));
+    assert_eq!(bitmap.claim_first_clear(Ordering::AcqRel), Some(4));
+    bitmap.fetch_and(!0b10, Ordering::AcqRel);
+    assert_eq!(bitmap.claim_first_clear(Ordering::AcqRel), Some(1));
+    assert_eq!(bitmap.load(Ordering::Acquire), 0b11111);
+}
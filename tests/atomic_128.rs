@@ -0,0 +1,120 @@
+#![cfg(all(feature = "atomic-128", target_has_atomic = "128", target_pointer_width = "64"))]
+
+use atomik::{DoubleWord, Ordering, Tagged};
+
+#[test]
+fn should_compare_exchange_pointer_with_counter() {
+    let mut first = 1u32;
+    let mut second = 2u32;
+    let first = &mut first as *mut u32;
+    let second = &mut second as *mut u32;
+
+    let head = DoubleWord::new(first, 0usize);
+    assert_eq!(head.load(Ordering::Acquire), (first, 0));
+
+    assert_eq!(head.compare_exchange((first, 0), (second, 1), Ordering::AcqRel, Ordering::Acquire), Ok((first, 0)));
+    //Pointer matches, but counter is stale
+    assert_eq!(head.compare_exchange((second, 0), (first, 2), Ordering::AcqRel, Ordering::Acquire), Err((second, 1)));
+    assert_eq!(head.compare_exchange((second, 1), (first, 2), Ordering::AcqRel, Ordering::Acquire), Ok((second, 1)));
+
+    head.store(second, 3, Ordering::Release);
+    assert_eq!(head.into_inner(), (second, 3));
+}
+
+#[test]
+fn should_distinguish_aba_via_tag() {
+    let mut a = 1u32;
+    let mut b = 2u32;
+    let a = &mut a as *mut u32;
+    let b = &mut b as *mut u32;
+
+    let head = Tagged::new(a);
+    let observed = head.load(Ordering::Acquire);
+    assert_eq!(observed, (a, 0));
+
+    //Another thread changes A -> B -> A
+    assert_eq!(head.compare_exchange(observed, b, Ordering::AcqRel, Ordering::Acquire), Ok((a, 0)));
+    assert_eq!(head.compare_exchange((b, 1), a, Ordering::AcqRel, Ordering::Acquire), Ok((b, 1)));
+    assert_eq!(head.load_value(Ordering::Acquire), a);
+
+    //Value is the same, but tag reveals modification
+    assert_eq!(head.compare_exchange(observed, b, Ordering::AcqRel, Ordering::Acquire), Err((a, 2)));
+    assert_eq!(head.compare_exchange((a, 2), b, Ordering::AcqRel, Ordering::Acquire), Ok((a, 2)));
+    assert_eq!(head.into_inner(), (b, 3));
+}
+
+#[test]
+fn should_push_and_pop_treiber_stack() {
+    struct Node {
+        value: usize,
+        next: *mut Node,
+    }
+
+    struct Stack(Tagged<*mut Node>);
+    //Stack owns nodes, which are only accessed by the thread that popped them
+    unsafe impl Sync for Stack {}
+    unsafe impl Send for Stack {}
+
+    impl Stack {
+        fn push(&self, value: usize) {
+            let node = Box::into_raw(Box::new(Node { value, next: core::ptr::null_mut() }));
+            let mut current = self.0.load(Ordering::Acquire);
+            loop {
+                unsafe {
+                    (*node).next = current.0;
+                }
+                match self.0.compare_exchange(current, node, Ordering::AcqRel, Ordering::Acquire) {
+                    Ok(_) => break,
+                    Err(actual) => current = actual,
+                }
+            }
+        }
+
+        fn pop(&self) -> Option<usize> {
+            let mut current = self.0.load(Ordering::Acquire);
+            loop {
+                if current.0.is_null() {
+                    return None;
+                }
+                //Pops happen once pushers are finished, so head cannot be freed concurrently
+                let next = unsafe {
+                    (*current.0).next
+                };
+                match self.0.compare_exchange(current, next, Ordering::AcqRel, Ordering::Acquire) {
+                    Ok(_) => {
+                        let node = unsafe {
+                            Box::from_raw(current.0)
+                        };
+                        return Some(node.value);
+                    },
+                    Err(actual) => current = actual,
+                }
+            }
+        }
+    }
+
+    const PUSHES: usize = if cfg!(miri) { 20 } else { 1_000 };
+    let stack = std::sync::Arc::new(Stack(Tagged::new(core::ptr::null_mut())));
+
+    let pushers: Vec<_> = (0..2).map(|_| {
+        let stack = stack.clone();
+        std::thread::spawn(move || {
+            for value in 1..=PUSHES {
+                stack.push(value);
+            }
+        })
+    }).collect();
+    for pusher in pushers {
+        pusher.join().expect("pusher to finish");
+    }
+
+    let mut sum = 0;
+    let mut count = 0;
+    while let Some(value) = stack.pop() {
+        sum += value;
+        count += 1;
+    }
+    assert_eq!(count, PUSHES * 2);
+    assert_eq!(sum, PUSHES * (PUSHES + 1));
+    assert_eq!(stack.0.load(Ordering::Acquire).1, PUSHES * 4);
+}
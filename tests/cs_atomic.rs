@@ -0,0 +1,131 @@
+#![cfg(feature = "critical-section")]
+#![allow(clippy::legacy_numeric_constants, clippy::bool_assert_comparison)]
+
+use atomik::{CsAtomic, CriticalAtomicAccess, Ordering};
+
+#[test]
+fn cs_atomic_bool() {
+    static FLAG: CsAtomic::<bool> = CsAtomic::new(false);
+    assert_eq!(FLAG.load(Ordering::Relaxed), false);
+    FLAG.store(true, Ordering::Relaxed);
+    assert_eq!(FLAG.swap(false, Ordering::Relaxed), true);
+    assert_eq!(FLAG.compare_exchange(true, false, Ordering::Relaxed, Ordering::Relaxed), Err(false));
+    assert_eq!(FLAG.compare_exchange(false, true, Ordering::Relaxed, Ordering::Relaxed), Ok(false));
+    assert_eq!(FLAG.fetch_and(false, Ordering::Relaxed), true);
+    assert_eq!(FLAG.fetch_or(true, Ordering::Relaxed), false);
+    assert_eq!(FLAG.fetch_xor(false, Ordering::Relaxed), true);
+    assert_eq!(FLAG.load(Ordering::Relaxed), true);
+}
+
+macro_rules! impl_test_int {
+    ($ty:ident) => {
+        static NUM: CsAtomic::<$ty> = CsAtomic::new(0);
+
+        assert_eq!(NUM.load(Ordering::Relaxed), 0);
+        NUM.store(1, Ordering::Relaxed);
+        assert_eq!(NUM.swap(5, Ordering::Relaxed), 1);
+        assert_eq!(NUM.compare_exchange(5, 10, Ordering::Acquire, Ordering::Relaxed), Ok(5));
+        assert_eq!(NUM.compare_exchange(9, 20, Ordering::Acquire, Ordering::Relaxed), Err(10));
+        assert_eq!(NUM.compare_exchange_weak(10, 20, Ordering::Acquire, Ordering::Relaxed), Ok(10));
+
+        assert_eq!(NUM.fetch_sub(17, Ordering::Relaxed), 20);
+        assert_eq!(NUM.fetch_add(7, Ordering::Relaxed), 3);
+        assert_eq!(NUM.fetch_min(2, Ordering::Relaxed), 10);
+        assert_eq!(NUM.fetch_max(4, Ordering::Relaxed), 2);
+        assert_eq!(NUM.fetch_sub(4, Ordering::Relaxed), 4);
+        assert_eq!(NUM.fetch_add($ty::max_value(), Ordering::Relaxed), 0);
+        assert_eq!(NUM.fetch_add(1, Ordering::Relaxed), $ty::max_value());
+        assert_eq!(NUM.load(Ordering::Relaxed), $ty::min_value());
+
+        NUM.store(0b101101, Ordering::Relaxed);
+        assert_eq!(NUM.fetch_or(0b110011, Ordering::Relaxed), 0b101101);
+        NUM.store(0b101101, Ordering::Relaxed);
+        assert_eq!(NUM.fetch_xor(0b110011, Ordering::Relaxed), 0b101101);
+        assert_eq!(NUM.load(Ordering::Relaxed), 0b011110);
+        NUM.store(0x13, Ordering::Relaxed);
+        assert_eq!(NUM.fetch_nand(0x31, Ordering::Relaxed), 0x13);
+        assert_eq!(NUM.load(Ordering::Relaxed), !(0x13 & 0x31));
+        NUM.store(0b101101, Ordering::Relaxed);
+        assert_eq!(NUM.fetch_and(0b110011, Ordering::Relaxed), 0b101101);
+        assert_eq!(NUM.load(Ordering::Relaxed), 0b100001);
+
+        NUM.store(7, Ordering::Relaxed);
+        assert_eq!(NUM.fetch_update(Ordering::SeqCst, Ordering::SeqCst, |_| None), Err(7));
+        assert_eq!(NUM.fetch_update(Ordering::SeqCst, Ordering::SeqCst, |x| Some(x + 1)), Ok(7));
+        assert_eq!(NUM.fetch_update_relaxed(|x| Some(x + 1)), Ok(8));
+        assert_eq!(NUM.load(Ordering::SeqCst), 9);
+    };
+}
+
+#[test]
+fn should_check_cs_methods_work_on_u8() {
+    impl_test_int!(u8);
+}
+
+#[test]
+fn should_check_cs_methods_work_on_u16() {
+    impl_test_int!(u16);
+}
+
+#[test]
+fn should_check_cs_methods_work_on_u32() {
+    impl_test_int!(u32);
+}
+
+#[test]
+fn should_check_cs_methods_work_on_u64() {
+    impl_test_int!(u64);
+}
+
+#[test]
+fn should_check_cs_methods_work_on_i32() {
+    impl_test_int!(i32);
+}
+
+#[test]
+fn should_check_cs_methods_work_on_isize() {
+    impl_test_int!(isize);
+}
+
+#[test]
+fn should_allow_unaligned_type_in_cs_atomic() {
+    let value = CsAtomic::new([1u8, 2, 3, 4]);
+    assert_eq!(value.swap([4, 3, 2, 1], Ordering::Relaxed), [1, 2, 3, 4]);
+    assert_eq!(value.compare_exchange([4, 3, 2, 1], [0; 4], Ordering::Relaxed, Ordering::Relaxed), Ok([4, 3, 2, 1]));
+    assert_eq!(value.into_inner(), [0; 4]);
+}
+
+#[test]
+fn should_mix_cs_atomic_across_threads() {
+    static COUNTER: CsAtomic::<u32> = CsAtomic::new(0);
+
+    let workers: Vec<_> = (0..4).map(|_| std::thread::spawn(|| {
+        for _ in 0..1000 {
+            COUNTER.fetch_add(1, Ordering::Relaxed);
+        }
+    })).collect();
+
+    for worker in workers {
+        worker.join().expect("worker to finish");
+    }
+    assert_eq!(COUNTER.load(Ordering::Relaxed), 4000);
+}
+
+#[test]
+fn should_write_batch_within_single_critical_section() {
+    static HEAD: CsAtomic::<u32> = CsAtomic::new(0);
+    static TAIL: CsAtomic::<u16> = CsAtomic::new(0);
+    static READY: CsAtomic::<bool> = CsAtomic::new(false);
+
+    let result = READY.with_critical_batch(|access: &CriticalAtomicAccess| {
+        access.store(&HEAD, 10);
+        access.store(&TAIL, 20);
+        assert_eq!(access.swap(&HEAD, 11), 10);
+        assert_eq!(access.swap(&READY, true), false);
+        access.load(&HEAD) + access.load(&TAIL) as u32
+    });
+    assert_eq!(result, 31);
+    assert_eq!(HEAD.load(Ordering::Relaxed), 11);
+    assert_eq!(TAIL.load(Ordering::Relaxed), 20);
+    assert_eq!(READY.load(Ordering::Relaxed), true);
+}
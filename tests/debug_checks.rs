@@ -0,0 +1,30 @@
+#![cfg(feature = "debug-checks")]
+
+use atomik::{Atomic, Ordering};
+
+#[test]
+fn should_not_report_aba_without_contention() {
+    let num = Atomic::<u32>::new(1);
+    assert_eq!(num.fetch_update_aba_check(Ordering::SeqCst, Ordering::SeqCst, |x| Some(x + 1)), (Ok(1), false));
+    assert_eq!(num.fetch_update_aba_check(Ordering::SeqCst, Ordering::SeqCst, |_| None), (Err(2), false));
+}
+
+#[test]
+fn should_report_aba_observed_within_update_loop() {
+    static NUM: Atomic::<usize> = Atomic::new(1);
+    let mut calls = 0;
+
+    let result = NUM.fetch_update_aba_check(Ordering::SeqCst, Ordering::SeqCst, |prev| {
+        calls += 1;
+        //Script concurrent writer: A -> B, then B -> A
+        match calls {
+            1 => std::thread::spawn(|| NUM.store(2, Ordering::SeqCst)).join().expect("to store B"),
+            2 => std::thread::spawn(|| NUM.store(1, Ordering::SeqCst)).join().expect("to store A"),
+            _ => (),
+        }
+        Some(prev + 10)
+    });
+
+    assert_eq!(result, (Ok(1), true));
+    assert_eq!(NUM.load(Ordering::SeqCst), 11);
+}
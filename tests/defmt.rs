@@ -0,0 +1,13 @@
+#![cfg(feature = "defmt")]
+
+use atomik::Atomic;
+
+fn assert_format<T: defmt::Format>() {
+}
+
+#[test]
+fn should_implement_defmt_format() {
+    assert_format::<Atomic<u32>>();
+    assert_format::<Atomic<bool>>();
+    assert_format::<Atomic<i64>>();
+}
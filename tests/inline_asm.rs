@@ -0,0 +1,32 @@
+#![cfg(feature = "inline-asm")]
+
+use atomik::{Atomic, Ordering};
+
+#[test]
+fn should_match_fetch_add() {
+    let asm = Atomic::<u64>::new(u64::MAX - 2);
+    let std = Atomic::<u64>::new(u64::MAX - 2);
+
+    for val in [0, 1, 5, u64::MAX, 1 << 63, 3] {
+        assert_eq!(asm.fetch_add_asm(val, Ordering::SeqCst), std.fetch_add(val, Ordering::SeqCst));
+        assert_eq!(asm.load(Ordering::SeqCst), std.load(Ordering::SeqCst));
+    }
+}
+
+#[test]
+fn should_fetch_add_across_threads() {
+    static NUM: Atomic::<u64> = Atomic::new(0);
+    const THREADS: u64 = 4;
+    const INCREMENTS: u64 = 10000;
+
+    let workers: Vec<_> = (0..THREADS).map(|_| std::thread::spawn(|| {
+        for _ in 0..INCREMENTS {
+            NUM.fetch_add_asm(1, Ordering::Relaxed);
+        }
+    })).collect();
+
+    for worker in workers {
+        worker.join().expect("worker to finish");
+    }
+    assert_eq!(NUM.load(Ordering::Relaxed), THREADS * INCREMENTS);
+}
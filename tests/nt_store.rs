@@ -0,0 +1,27 @@
+#![cfg(feature = "nt-store")]
+
+use atomik::{Atomic, Ordering};
+
+#[test]
+fn should_stream_initialize_table() {
+    static READY: Atomic::<bool> = Atomic::new(false);
+    //Only used to initialize array, as atomic is not `Copy`
+    #[allow(clippy::declare_interior_mutable_const)]
+    const ZERO: Atomic<u32> = Atomic::new(0);
+    static TABLE: [Atomic<u32>; 64] = [ZERO; 64];
+    static WIDE: Atomic::<i64> = Atomic::new(0);
+
+    for (idx, elem) in TABLE.iter().enumerate() {
+        elem.store_nt(idx as u32 * 3, Ordering::Relaxed);
+    }
+    WIDE.store_nt(-1, Ordering::Relaxed);
+    READY.store(true, Ordering::Release);
+
+    std::thread::spawn(|| {
+        assert!(READY.load(Ordering::Acquire));
+        for (idx, elem) in TABLE.iter().enumerate() {
+            assert_eq!(elem.load(Ordering::Relaxed), idx as u32 * 3);
+        }
+        assert_eq!(WIDE.load(Ordering::Relaxed), -1);
+    }).join().expect("reader to finish");
+}
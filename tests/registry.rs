@@ -0,0 +1,19 @@
+#![cfg(feature = "registry")]
+
+use atomik::{Atomic, Ordering};
+
+#[test]
+fn should_dump_registered_atomics() {
+    let requests = Atomic::new_registered(0u64, "requests");
+    let is_ready = Atomic::new_registered(false, "is_ready");
+    requests.fetch_add(3, Ordering::Relaxed);
+    is_ready.store(true, Ordering::Relaxed);
+
+    let mut out = String::new();
+    atomik::write_registry(&mut out).expect("to write registry");
+    let lines: Vec<_> = out.lines().collect();
+    assert!(lines.contains(&"requests=3"));
+    assert!(lines.contains(&"is_ready=true"));
+
+    atomik::dump_registry();
+}
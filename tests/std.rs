@@ -0,0 +1,44 @@
+#![cfg(feature = "std")]
+
+use atomik::{Atomic, Ordering};
+
+#[test]
+fn should_catch_panic_in_fetch_update() {
+    let num = Atomic::<u32>::new(5);
+    let result = num.fetch_update_catch(Ordering::AcqRel, Ordering::Acquire, |x| {
+        if x == 5 {
+            panic!("unexpected value");
+        }
+        Some(x + 1)
+    });
+    let error = result.expect_err("to catch panic");
+    assert_eq!(error.downcast_ref::<&str>(), Some(&"unexpected value"));
+    assert_eq!(num.load(Ordering::Relaxed), 5);
+
+    let result = num.fetch_update_catch(Ordering::AcqRel, Ordering::Acquire, |x| Some(x + 1));
+    assert_eq!(result.expect("to not panic"), Ok(5));
+    assert_eq!(num.load(Ordering::Relaxed), 6);
+}
+
+#[cfg(debug_assertions)]
+#[test]
+fn should_detect_concurrent_access_under_single_thread_guard() {
+    static NUM: Atomic::<u32> = Atomic::new(0);
+
+    let guard = NUM.single_thread_guard();
+    guard.store(1, Ordering::Relaxed);
+    //Nested guard on the same thread is fine
+    assert_eq!(NUM.single_thread_guard().swap(2, Ordering::Relaxed), 1);
+
+    let result = std::thread::spawn(|| {
+        NUM.single_thread_guard().load(Ordering::Relaxed)
+    }).join();
+    assert!(result.is_err());
+    assert_eq!(guard.load(Ordering::Relaxed), 2);
+    drop(guard);
+
+    let result = std::thread::spawn(|| {
+        NUM.single_thread_guard().load(Ordering::Relaxed)
+    }).join();
+    assert_eq!(result.expect("to not panic"), 2);
+}
@@ -0,0 +1,14 @@
+#![cfg(feature = "strict-test")]
+
+use atomik::{Atomic, Ordering};
+
+#[test]
+fn should_accept_any_ordering_when_remapped() {
+    let num = Atomic::<u32>::new(1);
+    //Invalid for load, but it is remapped to SeqCst
+    assert_eq!(num.load(Ordering::Release), 1);
+    num.store(2, Ordering::Acquire);
+    assert_eq!(num.compare_exchange(2, 3, Ordering::Relaxed, Ordering::Release), Ok(2));
+    assert_eq!(num.fetch_add(1, Ordering::Relaxed), 3);
+    assert_eq!(num.load(Ordering::Relaxed), 4);
+}